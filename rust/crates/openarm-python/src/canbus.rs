@@ -10,6 +10,9 @@ use crate::error::IntoPyResult;
 pub struct PyCanFrame {
     pub can_id: u32,
     pub data: Vec<u8>,
+    /// Kernel receive timestamp in microseconds since the Unix epoch, or `None`
+    /// for frames that were not read through the timestamping path.
+    pub timestamp_us: Option<u64>,
 }
 
 #[pymethods]
@@ -17,7 +20,11 @@ impl PyCanFrame {
     #[new]
     #[pyo3(signature = (can_id, data))]
     pub fn new(can_id: u32, data: Vec<u8>) -> Self {
-        Self { can_id, data }
+        Self {
+            can_id,
+            data,
+            timestamp_us: None,
+        }
     }
 
     fn __repr__(&self) -> String {
@@ -30,16 +37,14 @@ impl From<openarm::CanFrame> for PyCanFrame {
         Self {
             can_id: f.can_id,
             data: f.data,
+            timestamp_us: f.timestamp_us,
         }
     }
 }
 
 impl From<&PyCanFrame> for openarm::CanFrame {
     fn from(f: &PyCanFrame) -> Self {
-        Self {
-            can_id: f.can_id,
-            data: f.data.clone(),
-        }
+        openarm::CanFrame::new(f.can_id, f.data.clone())
     }
 }
 
@@ -50,6 +55,9 @@ pub struct PyCanFdFrame {
     pub can_id: u32,
     pub data: Vec<u8>,
     pub flags: u8,
+    /// Kernel receive timestamp in microseconds since the Unix epoch, or `None`
+    /// for frames that were not read through the timestamping path.
+    pub timestamp_us: Option<u64>,
 }
 
 #[pymethods]
@@ -57,7 +65,12 @@ impl PyCanFdFrame {
     #[new]
     #[pyo3(signature = (can_id, data, flags=0))]
     pub fn new(can_id: u32, data: Vec<u8>, flags: u8) -> Self {
-        Self { can_id, data, flags }
+        Self {
+            can_id,
+            data,
+            flags,
+            timestamp_us: None,
+        }
     }
 
     fn __repr__(&self) -> String {
@@ -74,17 +87,14 @@ impl From<openarm::CanFdFrame> for PyCanFdFrame {
             can_id: f.can_id,
             data: f.data,
             flags: f.flags,
+            timestamp_us: f.timestamp_us,
         }
     }
 }
 
 impl From<&PyCanFdFrame> for openarm::CanFdFrame {
     fn from(f: &PyCanFdFrame) -> Self {
-        Self {
-            can_id: f.can_id,
-            data: f.data.clone(),
-            flags: f.flags,
-        }
+        openarm::CanFdFrame::new(f.can_id, f.data.clone(), f.flags)
     }
 }
 
@@ -166,6 +176,14 @@ impl PyCANSocket {
         self.inner.set_recv_timeout(timeout_us).into_py_result()
     }
 
+    /// Install kernel receive filters as `(can_id, can_mask)` pairs.
+    ///
+    /// A frame is admitted when `frame_id & can_mask == can_id & can_mask`,
+    /// matching python-can's `can_filters`. An empty list drops all traffic.
+    pub fn set_filters(&mut self, filters: Vec<(u32, u32)>) -> PyResult<()> {
+        self.inner.set_filters(&filters).into_py_result()
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "CANSocket(interface='{}', enable_fd={}, open={})",
@@ -2,10 +2,13 @@
 
 use pyo3::prelude::*;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use openarm::OpenArmInterface;
 
 use crate::damiao_motor::{
-    PyCallbackMode, PyControlMode, PyMITParam, PyMotor, PyMotorType, PyMotorVariable,
-    PyPosForceParam, PyPosVelParam,
+    PyCANPacket, PyCallbackMode, PyControlMode, PyMITParam, PyMotor, PyMotorFaultStatus,
+    PyMotorType, PyMotorVariable, PyParamResult, PyPosForceParam, PyPosVelParam,
 };
 use crate::error::IntoPyResult;
 
@@ -24,6 +27,33 @@ impl PyOpenArm {
         Ok(Self { inner })
     }
 
+    /// Build an OpenArm from a YAML or TOML configuration file.
+    ///
+    /// The CAN interface is supplied separately so one layout file can be
+    /// reused across buses.
+    #[staticmethod]
+    pub fn from_config(path: String, can_interface: String) -> PyResult<Self> {
+        let inner = openarm::OpenArm::from_config(path, can_interface).into_py_result()?;
+        Ok(Self { inner })
+    }
+
+    /// Build a fully populated OpenArm from a robot configuration file.
+    #[staticmethod]
+    pub fn from_robot_config(path: String) -> PyResult<Self> {
+        let inner = openarm::OpenArm::from_robot_config(path).into_py_result()?;
+        Ok(Self { inner })
+    }
+
+    /// Look up an arm joint index by its configured name.
+    pub fn arm_joint_index(&self, name: &str) -> Option<usize> {
+        self.inner.arm_joint_index(name)
+    }
+
+    /// Arm joint names in index order, as recorded from the configuration.
+    pub fn arm_joint_names(&self) -> Vec<String> {
+        self.inner.arm_joint_names().to_vec()
+    }
+
     /// Initialize arm motors.
     #[pyo3(signature = (motor_types, send_can_ids, recv_can_ids, control_modes=None))]
     pub fn init_arm_motors(
@@ -65,6 +95,25 @@ impl PyOpenArm {
         PyGripperComponent::from_ref(self.inner.gripper())
     }
 
+    /// Start background telemetry streaming at the given period (microseconds).
+    ///
+    /// A background thread refreshes and receives state for every motor each
+    /// period; the per-motor getters then return the freshest values without
+    /// the caller managing the request/receive loop. A no-op if already running.
+    pub fn start_streaming(&mut self, period_us: u64) {
+        self.inner.start_streaming(period_us);
+    }
+
+    /// Stop background telemetry streaming, if running.
+    pub fn stop_streaming(&mut self) {
+        self.inner.stop_streaming();
+    }
+
+    /// Check whether background telemetry streaming is running.
+    pub fn is_streaming(&self) -> bool {
+        self.inner.is_streaming()
+    }
+
     /// Enable all motors (arm and gripper).
     pub fn enable_all(&self) -> PyResult<()> {
         self.inner.enable_all().into_py_result()
@@ -205,6 +254,26 @@ impl PyArmComponent {
         self.make_inner().query_param_one(index, rid.into()).into_py_result()
     }
 
+    /// Write a register by value on one motor, returning the acknowledged result.
+    #[pyo3(signature = (index, rid, value, timeout_us=100_000))]
+    pub fn set_param_one(
+        &self,
+        index: usize,
+        rid: PyMotorVariable,
+        value: f64,
+        timeout_us: u64,
+    ) -> PyResult<PyParamResult> {
+        self.make_inner()
+            .set_param_one(index, rid.into(), value, Duration::from_micros(timeout_us))
+            .map(Into::into)
+            .into_py_result()
+    }
+
+    /// Write a register by value on all motors.
+    pub fn set_param_all(&self, rid: PyMotorVariable, value: f64) -> PyResult<()> {
+        self.make_inner().set_param_all(rid.into(), value).into_py_result()
+    }
+
     /// MIT control for one motor.
     pub fn mit_control_one(&self, index: usize, param: &PyMITParam) -> PyResult<()> {
         self.make_inner().mit_control_one(index, &param.into()).into_py_result()
@@ -238,6 +307,21 @@ impl PyArmComponent {
         self.make_inner().posforce_control_all(&core_params).into_py_result()
     }
 
+    /// Clear the amplifier fault latch for one motor.
+    pub fn clear_faults_one(&self, index: usize) -> PyResult<()> {
+        self.make_inner().clear_faults_one(index).into_py_result()
+    }
+
+    /// Clear the amplifier fault latch for all motors.
+    pub fn clear_faults_all(&self) -> PyResult<()> {
+        self.make_inner().clear_faults_all().into_py_result()
+    }
+
+    /// Decode the structured fault status for one motor.
+    pub fn fault_status(&self, index: usize) -> PyResult<PyMotorFaultStatus> {
+        self.make_inner().fault_status(index).map(Into::into).into_py_result()
+    }
+
     /// Set control mode for one motor.
     pub fn set_control_mode_one(&self, index: usize, mode: PyControlMode) -> PyResult<()> {
         self.make_inner().set_control_mode_one(index, mode.into()).into_py_result()
@@ -429,6 +513,41 @@ impl PyGripperComponent {
         self.make_inner().posforce_control_all(&core_params).into_py_result()
     }
 
+    /// Clear the amplifier fault latch for one motor.
+    pub fn clear_faults_one(&self, index: usize) -> PyResult<()> {
+        self.make_inner().clear_faults_one(index).into_py_result()
+    }
+
+    /// Clear the amplifier fault latch for all motors.
+    pub fn clear_faults_all(&self) -> PyResult<()> {
+        self.make_inner().clear_faults_all().into_py_result()
+    }
+
+    /// Decode the structured fault status for one motor.
+    pub fn fault_status(&self, index: usize) -> PyResult<PyMotorFaultStatus> {
+        self.make_inner().fault_status(index).map(Into::into).into_py_result()
+    }
+
+    /// Write a register by value on one motor, returning the acknowledged result.
+    #[pyo3(signature = (index, rid, value, timeout_us=100_000))]
+    pub fn set_param_one(
+        &self,
+        index: usize,
+        rid: PyMotorVariable,
+        value: f64,
+        timeout_us: u64,
+    ) -> PyResult<PyParamResult> {
+        self.make_inner()
+            .set_param_one(index, rid.into(), value, Duration::from_micros(timeout_us))
+            .map(Into::into)
+            .into_py_result()
+    }
+
+    /// Write a register by value on all motors.
+    pub fn set_param_all(&self, rid: PyMotorVariable, value: f64) -> PyResult<()> {
+        self.make_inner().set_param_all(rid.into(), value).into_py_result()
+    }
+
     /// Set control mode.
     pub fn set_control_mode_one(&self, index: usize, mode: PyControlMode) -> PyResult<()> {
         self.make_inner().set_control_mode_one(index, mode.into()).into_py_result()
@@ -443,3 +562,84 @@ impl PyGripperComponent {
         format!("GripperComponent(motors={})", self.motors.len())
     }
 }
+
+/// Transport-agnostic, name-keyed group of joint motors.
+///
+/// Owns the motors and produces the CAN packets the caller sends on its own
+/// bus, rather than opening a socket like [`PyOpenArm`].
+#[pyclass(name = "MotorGroup")]
+pub struct PyMotorGroup {
+    inner: openarm::MotorGroup,
+}
+
+#[pymethods]
+impl PyMotorGroup {
+    /// Build a motor group from a YAML or TOML configuration file.
+    #[staticmethod]
+    pub fn from_config_file(path: String) -> PyResult<Self> {
+        let inner = openarm::MotorGroup::from_config_file(path).into_py_result()?;
+        Ok(Self { inner })
+    }
+
+    /// Number of joints in the group.
+    pub fn motor_count(&self) -> usize {
+        self.inner.motor_count()
+    }
+
+    /// Ordered joint names.
+    pub fn names(&self) -> Vec<String> {
+        self.inner.names().to_vec()
+    }
+
+    /// Get a joint motor by index.
+    pub fn get_motor(&self, index: usize) -> PyResult<PyMotor> {
+        self.inner.get_motor(index).map(|m| PyMotor::from(m.clone())).into_py_result()
+    }
+
+    /// Get a joint motor by name.
+    pub fn get_motor_by_name(&self, name: &str) -> PyResult<PyMotor> {
+        self.inner
+            .get_motor_by_name(name)
+            .map(|m| PyMotor::from(m.clone()))
+            .into_py_result()
+    }
+
+    /// Enable commands for every joint, in joint order.
+    pub fn enable_all(&self) -> Vec<PyCANPacket> {
+        self.inner.enable_all().into_iter().map(Into::into).collect()
+    }
+
+    /// Disable commands for every joint, in joint order.
+    pub fn disable_all(&self) -> Vec<PyCANPacket> {
+        self.inner.disable_all().into_iter().map(Into::into).collect()
+    }
+
+    /// Set-zero commands for every joint, in joint order.
+    pub fn set_zero_all(&self) -> Vec<PyCANPacket> {
+        self.inner.set_zero_all().into_iter().map(Into::into).collect()
+    }
+
+    /// MIT control commands for the whole group, in joint order.
+    pub fn mit_control(
+        &self,
+        positions: Vec<f64>,
+        velocities: Vec<f64>,
+        torques: Vec<f64>,
+        kps: Vec<f64>,
+        kds: Vec<f64>,
+    ) -> PyResult<Vec<PyCANPacket>> {
+        self.inner
+            .mit_control(&positions, &velocities, &torques, &kps, &kds)
+            .map(|packets| packets.into_iter().map(Into::into).collect())
+            .into_py_result()
+    }
+
+    /// Route an incoming feedback frame to the matching joint by `recv_can_id`.
+    pub fn apply_feedback(&self, recv_can_id: u32, data: Vec<u8>) -> bool {
+        self.inner.apply_feedback(recv_can_id, &data)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MotorGroup(joints={})", self.inner.motor_count())
+    }
+}
@@ -2,6 +2,18 @@
 
 use pyo3::prelude::*;
 
+/// Convert radians to degrees.
+#[pyfunction]
+pub fn rad_to_deg(rad: f64) -> f64 {
+    openarm::rad_to_deg(rad)
+}
+
+/// Convert degrees to radians.
+#[pyfunction]
+pub fn deg_to_rad(deg: f64) -> f64 {
+    openarm::deg_to_rad(deg)
+}
+
 
 /// Motor types supported by the Damiao motor family.
 #[pyclass(name = "MotorType", eq, eq_int)]
@@ -62,6 +74,32 @@ impl From<openarm::MotorType> for PyMotorType {
     }
 }
 
+#[pymethods]
+impl PyMotorType {
+    /// Pack MIT parameters into a CANPacket using this model's limits.
+    ///
+    /// The packet's `send_can_id` is left at 0; this is for off-bus debugging
+    /// and unit-testing of the MIT frame layout.
+    pub fn pack_mit(&self, param: &PyMITParam) -> PyCANPacket {
+        openarm::MotorType::from(*self)
+            .pack_mit(&param.into())
+            .into()
+    }
+
+    /// Unpack a feedback payload into a MotorStateResult using this model's
+    /// limits.
+    pub fn unpack_state(&self, data: Vec<u8>) -> PyMotorStateResult {
+        let mut result: PyMotorStateResult =
+            openarm::MotorType::from(*self).unpack_state(&data).into();
+        if let Some(first) = data.first() {
+            let error = openarm::MotorError::from_code(first >> 4);
+            result.enabled = error.is_enabled();
+            result.error = error.into();
+        }
+        result
+    }
+}
+
 /// Control modes for motor operation.
 #[pyclass(name = "ControlMode", eq, eq_int)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -269,6 +307,18 @@ impl PyLimitParam {
         Self { p_max, v_max, t_max }
     }
 
+    /// Map a physical value in `[-max, max]` to an unsigned integer of `bits`.
+    #[staticmethod]
+    pub fn float_to_uint(value: f64, max: f64, bits: u32) -> u32 {
+        openarm::LimitParam::float_to_uint(value, max, bits)
+    }
+
+    /// Inverse of `float_to_uint`.
+    #[staticmethod]
+    pub fn uint_to_float(raw: u32, max: f64, bits: u32) -> f64 {
+        openarm::LimitParam::uint_to_float(raw, max, bits)
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "LimitParam(p_max={}, v_max={}, t_max={})",
@@ -287,6 +337,10 @@ pub struct PyMotorStateResult {
     pub t_mos: i32,
     pub t_rotor: i32,
     pub valid: bool,
+    /// Decoded amplifier run/fault state from the status nibble.
+    pub error: PyMotorError,
+    /// Whether the amplifier reported itself enabled.
+    pub enabled: bool,
 }
 
 #[pymethods]
@@ -308,13 +362,22 @@ impl PyMotorStateResult {
             t_mos,
             t_rotor,
             valid,
+            error: PyMotorError::Disabled,
+            enabled: false,
         }
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "MotorStateResult(position={}, velocity={}, torque={}, t_mos={}, t_rotor={}, valid={})",
-            self.position, self.velocity, self.torque, self.t_mos, self.t_rotor, self.valid
+            "MotorStateResult(position={}, velocity={}, torque={}, t_mos={}, t_rotor={}, valid={}, error={}, enabled={})",
+            self.position,
+            self.velocity,
+            self.torque,
+            self.t_mos,
+            self.t_rotor,
+            self.valid,
+            openarm::MotorError::from(self.error).name(),
+            self.enabled
         )
     }
 }
@@ -328,6 +391,127 @@ impl From<openarm::MotorStateResult> for PyMotorStateResult {
             t_mos: r.t_mos,
             t_rotor: r.t_rotor,
             valid: r.valid,
+            error: PyMotorError::Disabled,
+            enabled: false,
+        }
+    }
+}
+
+/// Structured amplifier fault/run status decoded from a feedback frame.
+#[pyclass(name = "MotorFaultStatus", get_all)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PyMotorFaultStatus {
+    pub code: u8,
+    pub enabled: bool,
+    pub overvoltage: bool,
+    pub undervoltage: bool,
+    pub overcurrent: bool,
+    pub mos_overtemp: bool,
+    pub rotor_overtemp: bool,
+    pub comm_lost: bool,
+    pub overload: bool,
+}
+
+#[pymethods]
+impl PyMotorFaultStatus {
+    #[staticmethod]
+    pub fn from_code(code: u8) -> Self {
+        openarm::MotorFaultStatus::from_code(code).into()
+    }
+
+    /// Whether the code encodes a fault rather than a run state.
+    pub fn is_fault(&self) -> bool {
+        self.code >= 8
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MotorFaultStatus(code={}, enabled={}, overvoltage={}, undervoltage={}, overcurrent={}, mos_overtemp={}, rotor_overtemp={}, comm_lost={}, overload={})",
+            self.code,
+            self.enabled,
+            self.overvoltage,
+            self.undervoltage,
+            self.overcurrent,
+            self.mos_overtemp,
+            self.rotor_overtemp,
+            self.comm_lost,
+            self.overload
+        )
+    }
+}
+
+impl From<openarm::MotorFaultStatus> for PyMotorFaultStatus {
+    fn from(s: openarm::MotorFaultStatus) -> Self {
+        Self {
+            code: s.code,
+            enabled: s.enabled,
+            overvoltage: s.overvoltage,
+            undervoltage: s.undervoltage,
+            overcurrent: s.overcurrent,
+            mos_overtemp: s.mos_overtemp,
+            rotor_overtemp: s.rotor_overtemp,
+            comm_lost: s.comm_lost,
+            overload: s.overload,
+        }
+    }
+}
+
+/// Amplifier run/fault state decoded from a feedback frame's status nibble.
+#[pyclass(name = "MotorError", eq, eq_int)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PyMotorError {
+    #[default]
+    Disabled = 0,
+    Enabled = 1,
+    Overvoltage = 8,
+    Undervoltage = 9,
+    Overcurrent = 0xA,
+    MosOvertemp = 0xB,
+    RotorOvertemp = 0xC,
+    CommLoss = 0xD,
+    Overload = 0xE,
+}
+
+#[pymethods]
+impl PyMotorError {
+    /// Whether the code names a fault rather than a run state.
+    pub fn is_fault(&self) -> bool {
+        !matches!(self, PyMotorError::Disabled | PyMotorError::Enabled)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MotorError.{}", openarm::MotorError::from(*self).name())
+    }
+}
+
+impl From<openarm::MotorError> for PyMotorError {
+    fn from(e: openarm::MotorError) -> Self {
+        match e {
+            openarm::MotorError::Disabled => PyMotorError::Disabled,
+            openarm::MotorError::Enabled => PyMotorError::Enabled,
+            openarm::MotorError::Overvoltage => PyMotorError::Overvoltage,
+            openarm::MotorError::Undervoltage => PyMotorError::Undervoltage,
+            openarm::MotorError::Overcurrent => PyMotorError::Overcurrent,
+            openarm::MotorError::MosOvertemp => PyMotorError::MosOvertemp,
+            openarm::MotorError::RotorOvertemp => PyMotorError::RotorOvertemp,
+            openarm::MotorError::CommLoss => PyMotorError::CommLoss,
+            openarm::MotorError::Overload => PyMotorError::Overload,
+        }
+    }
+}
+
+impl From<PyMotorError> for openarm::MotorError {
+    fn from(e: PyMotorError) -> Self {
+        match e {
+            PyMotorError::Disabled => openarm::MotorError::Disabled,
+            PyMotorError::Enabled => openarm::MotorError::Enabled,
+            PyMotorError::Overvoltage => openarm::MotorError::Overvoltage,
+            PyMotorError::Undervoltage => openarm::MotorError::Undervoltage,
+            PyMotorError::Overcurrent => openarm::MotorError::Overcurrent,
+            PyMotorError::MosOvertemp => openarm::MotorError::MosOvertemp,
+            PyMotorError::RotorOvertemp => openarm::MotorError::RotorOvertemp,
+            PyMotorError::CommLoss => openarm::MotorError::CommLoss,
+            PyMotorError::Overload => openarm::MotorError::Overload,
         }
     }
 }
@@ -497,11 +681,65 @@ impl From<openarm::CANPacket> for PyCANPacket {
     }
 }
 
+/// Unit selection for angle and velocity values crossing the Python surface.
+///
+/// The internal [`openarm::Motor`] always works in SI radians; these units only
+/// change how values are presented to and accepted from Python. `RADIANS` and
+/// `DEGREES` apply to angles; `RAD_PER_S` and `RPM` apply to velocities.
+#[pyclass(name = "Units", eq, eq_int)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum PyUnits {
+    RADIANS,
+    DEGREES,
+    RAD_PER_S,
+    RPM,
+}
+
+/// Radians per second in one RPM.
+const RAD_PER_S_PER_RPM: f64 = std::f64::consts::TAU / 60.0;
+
+impl PyUnits {
+    /// Convert an angle in radians to this unit.
+    fn angle_from_si(self, rad: f64) -> f64 {
+        match self {
+            PyUnits::DEGREES => openarm::rad_to_deg(rad),
+            _ => rad,
+        }
+    }
+
+    /// Convert an angle expressed in this unit to radians.
+    fn angle_to_si(self, value: f64) -> f64 {
+        match self {
+            PyUnits::DEGREES => openarm::deg_to_rad(value),
+            _ => value,
+        }
+    }
+
+    /// Convert a velocity in rad/s to this unit.
+    fn velocity_from_si(self, rad_per_s: f64) -> f64 {
+        match self {
+            PyUnits::RPM => rad_per_s / RAD_PER_S_PER_RPM,
+            _ => rad_per_s,
+        }
+    }
+
+    /// Convert a velocity expressed in this unit to rad/s.
+    fn velocity_to_si(self, value: f64) -> f64 {
+        match self {
+            PyUnits::RPM => value * RAD_PER_S_PER_RPM,
+            _ => value,
+        }
+    }
+}
+
 /// Motor state container wrapper.
 #[pyclass(name = "Motor")]
 #[derive(Clone)]
 pub struct PyMotor {
     pub(crate) inner: openarm::Motor,
+    angle_unit: PyUnits,
+    velocity_unit: PyUnits,
 }
 
 #[pymethods]
@@ -516,9 +754,35 @@ impl PyMotor {
     ) -> Self {
         Self {
             inner: openarm::Motor::new(motor_type.into(), send_can_id, recv_can_id, control_mode.into()),
+            angle_unit: PyUnits::RADIANS,
+            velocity_unit: PyUnits::RAD_PER_S,
         }
     }
 
+    /// The angle unit used for position reads and `q` command fields.
+    #[getter]
+    pub fn get_angle_unit(&self) -> PyUnits {
+        self.angle_unit
+    }
+
+    /// Select the angle unit (`RADIANS` or `DEGREES`) for this motor.
+    #[setter]
+    pub fn set_angle_unit(&mut self, unit: PyUnits) {
+        self.angle_unit = unit;
+    }
+
+    /// The velocity unit used for velocity reads and `dq` command fields.
+    #[getter]
+    pub fn get_velocity_unit(&self) -> PyUnits {
+        self.velocity_unit
+    }
+
+    /// Select the velocity unit (`RAD_PER_S` or `RPM`) for this motor.
+    #[setter]
+    pub fn set_velocity_unit(&mut self, unit: PyUnits) {
+        self.velocity_unit = unit;
+    }
+
     /// Get the motor type.
     #[getter]
     pub fn get_motor_type(&self) -> PyMotorType {
@@ -543,17 +807,85 @@ impl PyMotor {
         self.inner.control_mode().into()
     }
 
-    /// Get the current position (rad).
+    /// Get the raw-frame zero offset (rad).
+    #[getter]
+    pub fn get_offset(&self) -> f64 {
+        self.inner.offset()
+    }
+
+    /// Set the raw-frame zero offset (rad).
+    #[setter]
+    pub fn set_offset(&mut self, offset: f64) {
+        self.inner.set_offset(offset);
+    }
+
+    /// Get the gearbox reduction ratio.
+    #[getter]
+    pub fn get_reduction_ratio(&self) -> f64 {
+        self.inner.reduction_ratio()
+    }
+
+    /// Set the gearbox reduction ratio.
+    #[setter]
+    pub fn set_reduction_ratio(&mut self, reduction_ratio: f64) {
+        self.inner.set_reduction_ratio(reduction_ratio);
+    }
+
+    /// Configure the soft limits on logical position (rad), velocity (rad/s),
+    /// and torque (Nm) commands. Each limit is given as a `(min, max)` range.
+    pub fn set_limits(
+        &mut self,
+        position: (f64, f64),
+        velocity: (f64, f64),
+        torque: (f64, f64),
+    ) {
+        self.inner.set_limits(
+            openarm::Limit::new(position.0, position.1),
+            openarm::Limit::new(velocity.0, velocity.1),
+            openarm::Limit::new(torque.0, torque.1),
+        );
+    }
+
+    /// Whether out-of-range commands raise instead of being clamped.
+    #[getter]
+    pub fn get_strict_limits(&self) -> bool {
+        self.inner.strict_limits()
+    }
+
+    /// Enable or disable strict limit enforcement. In strict mode a command
+    /// outside the configured range raises `ValueError` instead of being
+    /// clamped.
+    #[setter]
+    pub fn set_strict_limits(&mut self, strict: bool) {
+        self.inner.set_strict_limits(strict);
+    }
+
+    /// Get the raw position decoded from CAN (rad), before any transform.
+    pub fn get_raw_position(&self) -> f64 {
+        self.inner.get_raw_position()
+    }
+
+    /// Get the raw velocity decoded from CAN (rad/s), before any transform.
+    pub fn get_raw_velocity(&self) -> f64 {
+        self.inner.get_raw_velocity()
+    }
+
+    /// Get the raw torque decoded from CAN (Nm), before any transform.
+    pub fn get_raw_torque(&self) -> f64 {
+        self.inner.get_raw_torque()
+    }
+
+    /// Get the logical position in the motor's configured angle unit.
     pub fn get_position(&self) -> f64 {
-        self.inner.get_position()
+        self.angle_unit.angle_from_si(self.inner.get_position())
     }
 
-    /// Get the current velocity (rad/s).
+    /// Get the logical velocity in the motor's configured velocity unit.
     pub fn get_velocity(&self) -> f64 {
-        self.inner.get_velocity()
+        self.velocity_unit.velocity_from_si(self.inner.get_velocity())
     }
 
-    /// Get the current torque (Nm).
+    /// Get the logical torque (Nm).
     pub fn get_torque(&self) -> f64 {
         self.inner.get_torque()
     }
@@ -573,6 +905,11 @@ impl PyMotor {
         self.inner.is_enabled()
     }
 
+    /// Decode the last state frame's status nibble into a fault status.
+    pub fn fault_status(&self) -> PyMotorFaultStatus {
+        self.inner.fault_status().into()
+    }
+
     /// Get a temporary parameter value.
     pub fn get_temp_param(&self, rid: i32) -> Option<f64> {
         self.inner.get_temp_param(rid)
@@ -593,7 +930,41 @@ impl PyMotor {
 
 impl From<openarm::Motor> for PyMotor {
     fn from(m: openarm::Motor) -> Self {
-        Self { inner: m }
+        Self {
+            inner: m,
+            angle_unit: PyUnits::RADIANS,
+            velocity_unit: PyUnits::RAD_PER_S,
+        }
+    }
+}
+
+impl PyMotor {
+    /// Convert an MIT command's `q`/`dq` from the motor's display units to SI.
+    pub(crate) fn mit_to_si(&self, p: &PyMITParam) -> openarm::MITParam {
+        openarm::MITParam {
+            kp: p.kp,
+            kd: p.kd,
+            q: self.angle_unit.angle_to_si(p.q),
+            dq: self.velocity_unit.velocity_to_si(p.dq),
+            tau: p.tau,
+        }
+    }
+
+    /// Convert a pos-vel command's `q`/`dq` from the motor's display units to SI.
+    pub(crate) fn posvel_to_si(&self, p: &PyPosVelParam) -> openarm::PosVelParam {
+        openarm::PosVelParam {
+            q: self.angle_unit.angle_to_si(p.q),
+            dq: self.velocity_unit.velocity_to_si(p.dq),
+        }
+    }
+
+    /// Convert a pos-force command's `q`/`dq` from the motor's display units to SI.
+    pub(crate) fn posforce_to_si(&self, p: &PyPosForceParam) -> openarm::PosForceParam {
+        openarm::PosForceParam {
+            q: self.angle_unit.angle_to_si(p.q),
+            dq: self.velocity_unit.velocity_to_si(p.dq),
+            i: p.i,
+        }
     }
 }
 
@@ -636,19 +1007,19 @@ impl PyCanPacketEncoder {
     /// Create MIT control command.
     #[staticmethod]
     pub fn create_mit_control_command(motor: &PyMotor, param: &PyMITParam) -> PyCANPacket {
-        openarm::CanPacketEncoder::create_mit_control_command(&motor.inner, &param.into()).into()
+        openarm::CanPacketEncoder::create_mit_control_command(&motor.inner, &motor.mit_to_si(param)).into()
     }
 
     /// Create position-velocity control command.
     #[staticmethod]
     pub fn create_posvel_control_command(motor: &PyMotor, param: &PyPosVelParam) -> PyCANPacket {
-        openarm::CanPacketEncoder::create_posvel_control_command(&motor.inner, &param.into()).into()
+        openarm::CanPacketEncoder::create_posvel_control_command(&motor.inner, &motor.posvel_to_si(param)).into()
     }
 
     /// Create position-force control command.
     #[staticmethod]
     pub fn create_posforce_control_command(motor: &PyMotor, param: &PyPosForceParam) -> PyCANPacket {
-        openarm::CanPacketEncoder::create_posforce_control_command(&motor.inner, &param.into()).into()
+        openarm::CanPacketEncoder::create_posforce_control_command(&motor.inner, &motor.posforce_to_si(param)).into()
     }
 
     /// Create set control mode command.
@@ -662,6 +1033,52 @@ impl PyCanPacketEncoder {
     pub fn create_query_param_command(motor: &PyMotor, rid: PyMotorVariable) -> PyCANPacket {
         openarm::CanPacketEncoder::create_query_param_command(&motor.inner, rid.into()).into()
     }
+
+    /// Create a write-parameter command.
+    ///
+    /// `value` is coerced to the register's declared wire type: physical
+    /// registers are packed as little-endian `f32`, while identifier and mode
+    /// registers are packed as integers. Writing a non-integral or
+    /// out-of-range value to an integer register raises `ValueError`.
+    #[staticmethod]
+    pub fn create_write_param_command(
+        motor: &PyMotor,
+        rid: PyMotorVariable,
+        value: f64,
+    ) -> PyResult<PyCANPacket> {
+        use openarm::ParamWireType;
+        use pyo3::exceptions::PyValueError;
+
+        let rid: openarm::MotorVariable = rid.into();
+        let param_value = match rid.wire_type() {
+            ParamWireType::F32 => openarm::ParamValue::F32(value as f32),
+            ParamWireType::U32 => {
+                if value.fract() != 0.0 || value < 0.0 || value > u32::MAX as f64 {
+                    return Err(PyValueError::new_err(format!(
+                        "value {} out of range for unsigned integer register",
+                        value
+                    )));
+                }
+                openarm::ParamValue::U32(value as u32)
+            }
+            ParamWireType::I32 => {
+                if value.fract() != 0.0 || value < i32::MIN as f64 || value > i32::MAX as f64 {
+                    return Err(PyValueError::new_err(format!(
+                        "value {} out of range for signed integer register",
+                        value
+                    )));
+                }
+                openarm::ParamValue::I32(value as i32)
+            }
+        };
+        Ok(openarm::CanPacketEncoder::create_write_param_command(&motor.inner, rid, param_value).into())
+    }
+
+    /// Create a save-parameters-to-flash command, persisting RAM writes.
+    #[staticmethod]
+    pub fn create_save_param_command(motor: &PyMotor) -> PyCANPacket {
+        openarm::CanPacketEncoder::create_save_param_command(&motor.inner).into()
+    }
 }
 
 /// CAN packet decoder wrapper.
@@ -679,7 +1096,17 @@ impl PyCanPacketDecoder {
     /// Parse motor state data from CAN frame.
     #[staticmethod]
     pub fn parse_motor_state_data(motor: &PyMotor, data: Vec<u8>) -> PyMotorStateResult {
-        openarm::CanPacketDecoder::parse_motor_state_data(&motor.inner, &data).into()
+        let mut result: PyMotorStateResult =
+            openarm::CanPacketDecoder::parse_motor_state_data(&motor.inner, &data).into();
+        // Present position/velocity in the motor's configured display units.
+        result.position = motor.angle_unit.angle_from_si(result.position);
+        result.velocity = motor.velocity_unit.velocity_from_si(result.velocity);
+        if let Some(first) = data.first() {
+            let error = openarm::MotorError::from_code(first >> 4);
+            result.enabled = error.is_enabled();
+            result.error = error.into();
+        }
+        result
     }
 
     /// Parse parameter data from CAN frame.
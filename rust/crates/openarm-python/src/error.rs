@@ -30,6 +30,37 @@ impl<T> IntoPyResult<T> for openarm::Result<T> {
             OpenArmError::ParamCountMismatch { expected, actual } => {
                 PyValueError::new_err(format!("Expected {} params, got {}", expected, actual))
             }
+            OpenArmError::ResponseTimeout(reg) => {
+                CANSocketException::new_err(format!("Timed out waiting for response to register {}", reg))
+            }
+            OpenArmError::BusError(err) => {
+                CANSocketException::new_err(format!("CAN bus error: {:?}", err))
+            }
+            OpenArmError::ReadOnlyRegister(reg) => {
+                PyValueError::new_err(format!("Register {} is read-only", reg))
+            }
+            OpenArmError::ConfigError(msg) => {
+                PyValueError::new_err(format!("Configuration error: {}", msg))
+            }
+            OpenArmError::LimitViolation {
+                joint,
+                quantity,
+                value,
+                min,
+                max,
+            } => PyValueError::new_err(format!(
+                "joint {} {} command {} violates safety limit [{}, {}]",
+                joint, quantity, value, min, max
+            )),
+            OpenArmError::CommandOutOfRange {
+                quantity,
+                value,
+                min,
+                max,
+            } => PyValueError::new_err(format!(
+                "{} command {} out of range [{}, {}]",
+                quantity, value, min, max
+            )),
         })
     }
 }
@@ -10,11 +10,12 @@ mod damiao_motor;
 mod error;
 
 use canbus::{PyCANDevice, PyCANSocket, PyCanFdFrame, PyCanFrame};
-use components::{PyArmComponent, PyGripperComponent, PyOpenArm};
+use components::{PyArmComponent, PyGripperComponent, PyMotorGroup, PyOpenArm};
 use damiao_motor::{
-    PyCANPacket, PyCallbackMode, PyCanPacketDecoder, PyCanPacketEncoder, PyControlMode,
-    PyLimitParam, PyMITParam, PyMotor, PyMotorStateResult, PyMotorType, PyMotorVariable,
-    PyParamResult, PyPosForceParam, PyPosVelParam,
+    deg_to_rad, rad_to_deg, PyCANPacket, PyCallbackMode, PyCanPacketDecoder, PyCanPacketEncoder,
+    PyControlMode, PyLimitParam, PyMITParam, PyMotor, PyMotorError, PyMotorFaultStatus,
+    PyMotorStateResult, PyMotorType, PyMotorVariable, PyParamResult, PyPosForceParam, PyPosVelParam,
+    PyUnits,
 };
 use error::CANSocketException;
 
@@ -26,11 +27,14 @@ fn openarm_can(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyMotorVariable>()?;
     m.add_class::<PyCallbackMode>()?;
     m.add_class::<PyControlMode>()?;
+    m.add_class::<PyUnits>()?;
 
     // Data structures
     m.add_class::<PyLimitParam>()?;
     m.add_class::<PyParamResult>()?;
     m.add_class::<PyMotorStateResult>()?;
+    m.add_class::<PyMotorFaultStatus>()?;
+    m.add_class::<PyMotorError>()?;
     m.add_class::<PyCanFrame>()?;
     m.add_class::<PyCanFdFrame>()?;
     m.add_class::<PyMITParam>()?;
@@ -45,9 +49,14 @@ fn openarm_can(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyOpenArm>()?;
     m.add_class::<PyArmComponent>()?;
     m.add_class::<PyGripperComponent>()?;
+    m.add_class::<PyMotorGroup>()?;
     m.add_class::<PyCanPacketEncoder>()?;
     m.add_class::<PyCanPacketDecoder>()?;
 
+    // Free functions
+    m.add_function(wrap_pyfunction!(rad_to_deg, m)?)?;
+    m.add_function(wrap_pyfunction!(deg_to_rad, m)?)?;
+
     // Exception
     m.add("CANSocketException", m.py().get_type::<CANSocketException>())?;
 
@@ -1,6 +1,7 @@
 //! CAN device trait and base implementations.
 
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use crate::damiao_motor::{CallbackMode, CanPacketDecoder, Motor};
 
@@ -52,6 +53,9 @@ impl CANDevice {
 /// Motor device state (internal).
 struct MotorDeviceState {
     callback_mode: CallbackMode,
+    /// Receive timestamp of the most recently processed frame, in microseconds
+    /// since the Unix epoch.
+    last_timestamp_us: Option<u64>,
 }
 
 /// Damiao motor CAN device implementation.
@@ -67,6 +71,7 @@ impl MotorDeviceCan {
             motor,
             state: Arc::new(Mutex::new(MotorDeviceState {
                 callback_mode: CallbackMode::STATE,
+                last_timestamp_us: None,
             })),
         }
     }
@@ -105,6 +110,25 @@ impl MotorDeviceCan {
         }
     }
 
+    /// Process incoming CAN data, recording the frame's receive timestamp.
+    ///
+    /// Behaves like [`process_callback`](Self::process_callback) but stores
+    /// `timestamp_us` (kernel receive time, in microseconds since the Unix
+    /// epoch) so callers can measure control-loop latency and detect stale
+    /// state via [`last_update_timestamp`](Self::last_update_timestamp).
+    pub fn process_callback_ts(&self, data: &[u8], timestamp_us: Option<u64>) {
+        if let Some(ts) = timestamp_us {
+            self.state.lock().unwrap().last_timestamp_us = Some(ts);
+        }
+        self.process_callback(data);
+    }
+
+    /// Receive timestamp of the last processed frame, in microseconds since the
+    /// Unix epoch, or `None` if no timestamped frame has been seen.
+    pub fn last_update_timestamp(&self) -> Option<u64> {
+        self.state.lock().unwrap().last_timestamp_us
+    }
+
     /// Clone for internal use.
     pub fn clone_inner(&self) -> Self {
         Self {
@@ -135,3 +159,151 @@ impl CANDeviceTrait for MotorDeviceCan {
         self.state.lock().unwrap().callback_mode = mode;
     }
 }
+
+/// Simulation state for a [`FakeMotorDeviceCan`].
+struct FakeMotorState {
+    callback_mode: CallbackMode,
+    /// Last commanded MIT setpoints.
+    target_q: f64,
+    target_dq: f64,
+    target_tau: f64,
+    /// Proportional gain from the last MIT command, used for the torque model.
+    kp: f64,
+    /// Instant of the last integration step.
+    last_update: Option<Instant>,
+}
+
+/// Software-simulated motor device for hardware-free testing.
+///
+/// Implements [`CANDeviceTrait`] but, instead of decoding real feedback frames,
+/// interprets the command frames handed to [`callback`](CANDeviceTrait::callback)
+/// and integrates a simple first-order model so the `ArmComponent`/
+/// `GripperComponent` control stack can be exercised in CI without a SocketCAN
+/// interface. Enable/disable and set-zero frames toggle `enabled` and reset the
+/// position, mirroring the real device.
+pub struct FakeMotorDeviceCan {
+    motor: Motor,
+    sim: Arc<Mutex<FakeMotorState>>,
+}
+
+impl FakeMotorDeviceCan {
+    /// Create a new simulated motor device.
+    pub fn new(motor: Motor) -> Self {
+        Self {
+            motor,
+            sim: Arc::new(Mutex::new(FakeMotorState {
+                callback_mode: CallbackMode::STATE,
+                target_q: 0.0,
+                target_dq: 0.0,
+                target_tau: 0.0,
+                kp: 0.0,
+                last_update: None,
+            })),
+        }
+    }
+
+    /// Get the motor.
+    pub fn motor(&self) -> &Motor {
+        &self.motor
+    }
+
+    /// Get a clone of the motor.
+    pub fn motor_clone(&self) -> Motor {
+        self.motor.clone()
+    }
+
+    /// Decode a MIT command frame and advance the model one step toward it.
+    fn step_mit(&self, data: &[u8]) {
+        let limits = self.motor.motor_type().get_limits();
+
+        let q_raw = ((data[0] as u32) << 8) | data[1] as u32;
+        let dq_raw = ((data[2] as u32) << 4) | ((data[3] >> 4) as u32);
+        let kp_raw = (((data[3] & 0x0F) as u32) << 8) | data[4] as u32;
+        let tau_raw = (((data[6] & 0x0F) as u32) << 8) | data[7] as u32;
+
+        let span = |raw: u32, max: f64, bits: u32| {
+            let max_raw = ((1u64 << bits) - 1) as f64;
+            -max + (raw as f64 / max_raw) * (2.0 * max)
+        };
+
+        let target_q = span(q_raw, limits.p_max, 16);
+        let target_dq = span(dq_raw, limits.v_max, 12);
+        let target_tau = span(tau_raw, limits.t_max, 12);
+        let kp = (kp_raw as f64 / 4095.0) * 500.0;
+
+        let now = Instant::now();
+        let (position, velocity, torque) = {
+            let mut sim = self.sim.lock().unwrap();
+            sim.target_q = target_q;
+            sim.target_dq = target_dq;
+            sim.target_tau = target_tau;
+            sim.kp = kp;
+
+            let dt = sim
+                .last_update
+                .map(|t| now.duration_since(t).as_secs_f64())
+                .unwrap_or(0.0);
+            sim.last_update = Some(now);
+
+            let position = self.motor.get_raw_position();
+            // Advance toward the target without overshooting.
+            let step = target_dq.abs() * dt;
+            let error = target_q - position;
+            let new_position = if error.abs() <= step {
+                target_q
+            } else {
+                position + step * error.signum()
+            };
+            let velocity = if dt > 0.0 {
+                (new_position - position) / dt
+            } else {
+                0.0
+            };
+            // Torque tracks the position error via the commanded gain.
+            let torque = kp * (target_q - new_position);
+            (new_position, velocity, torque)
+        };
+
+        self.motor.update_state(position, velocity, torque, 25, 25, 1);
+    }
+}
+
+impl CANDeviceTrait for FakeMotorDeviceCan {
+    fn send_can_id(&self) -> u32 {
+        self.motor.send_can_id()
+    }
+
+    fn recv_can_id(&self) -> u32 {
+        self.motor.recv_can_id()
+    }
+
+    fn callback(&self, _can_id: u32, data: &[u8]) {
+        if data.len() < 8 {
+            return;
+        }
+
+        // Special command frames are `0xFF * 7` followed by a type byte.
+        if data[..7] == [0xFF; 7] {
+            match data[7] {
+                0xFC => self.motor.set_enabled(true),
+                0xFD => self.motor.set_enabled(false),
+                0xFE => {
+                    self.motor.update_state(0.0, 0.0, 0.0, 25, 25, 1);
+                    self.sim.lock().unwrap().last_update = None;
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        self.step_mit(data);
+    }
+
+    fn get_callback_mode(&self) -> CallbackMode {
+        self.sim.lock().unwrap().callback_mode
+    }
+
+    fn set_callback_mode(&self, mode: CallbackMode) {
+        self.sim.lock().unwrap().callback_mode = mode;
+    }
+}
@@ -1,17 +1,431 @@
 //! CAN device collection for managing multiple devices.
 
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 
 use super::device::{CANDeviceTrait, MotorDeviceCan};
 use super::socket::CANSocket;
 use crate::damiao_motor::CallbackMode;
 use crate::error::Result;
 
+/// Selector deciding which frames a subscriber receives, in the style of an
+/// AF_PACKET filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    /// Match every frame on the bus (promiscuous sniffer).
+    All,
+    /// Match when `can_id & mask == id & mask`.
+    Specific { id: u32, mask: u32 },
+}
+
+impl Protocol {
+    /// Whether a frame with arbitration id `can_id` matches this selector.
+    pub fn matches(&self, can_id: u32) -> bool {
+        match self {
+            Protocol::All => true,
+            Protocol::Specific { id, mask } => can_id & mask == id & mask,
+        }
+    }
+}
+
+/// A closure invoked with the id and borrowed payload of every matching frame.
+type FrameListener = Box<dyn Fn(u32, &[u8]) + Send + Sync>;
+
+/// Action taken when a channel subscriber's bounded queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Evict the oldest queued frame to make room for the new one.
+    DropOldest,
+    /// Discard the incoming frame, leaving the queue untouched.
+    DropNewest,
+}
+
+/// Shared state behind a [`FrameReceiver`] and its producing listener.
+struct ChannelInner {
+    queue: Mutex<VecDeque<(u32, Vec<u8>)>>,
+    not_empty: Condvar,
+    capacity: usize,
+    policy: OverflowPolicy,
+}
+
+impl ChannelInner {
+    fn push(&self, can_id: u32, data: Vec<u8>) {
+        let mut queue = self.queue.lock().unwrap();
+        if queue.len() >= self.capacity {
+            match self.policy {
+                OverflowPolicy::DropNewest => return,
+                OverflowPolicy::DropOldest => {
+                    queue.pop_front();
+                }
+            }
+        }
+        queue.push_back((can_id, data));
+        self.not_empty.notify_one();
+    }
+}
+
+/// Receiving end of a channel subscription. Frames are pushed by the receive
+/// path and drained here on the consumer's own thread, decoupling bus I/O from
+/// decode cost.
+pub struct FrameReceiver {
+    inner: Arc<ChannelInner>,
+}
+
+impl FrameReceiver {
+    /// Pop the next frame, blocking until one is available.
+    pub fn recv(&self) -> (u32, Vec<u8>) {
+        let mut queue = self.inner.queue.lock().unwrap();
+        loop {
+            if let Some(frame) = queue.pop_front() {
+                return frame;
+            }
+            queue = self.inner.not_empty.wait(queue).unwrap();
+        }
+    }
+
+    /// Pop the next frame if one is queued, without blocking.
+    pub fn try_recv(&self) -> Option<(u32, Vec<u8>)> {
+        self.inner.queue.lock().unwrap().pop_front()
+    }
+
+    /// Number of frames currently queued.
+    pub fn len(&self) -> usize {
+        self.inner.queue.lock().unwrap().len()
+    }
+
+    /// Whether the queue is currently empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.queue.lock().unwrap().is_empty()
+    }
+}
+
+/// Registry of masked subscribers fanned out to by `dispatch_frame`.
+struct SubscriberTable {
+    next_id: u64,
+    entries: Vec<(u64, Protocol, FrameListener)>,
+}
+
+impl SubscriberTable {
+    fn new() -> Self {
+        Self {
+            next_id: 0,
+            entries: Vec::new(),
+        }
+    }
+}
+
+/// Tunables for the ISO-TP reassembly subsystem.
+#[derive(Debug, Clone, Copy)]
+pub struct ReassemblyConfig {
+    /// Partial messages idle for longer than this are discarded.
+    pub timeout: std::time::Duration,
+    /// Upper bound on concurrently tracked partial messages, to cap memory.
+    pub max_partial: usize,
+}
+
+impl Default for ReassemblyConfig {
+    fn default() -> Self {
+        Self {
+            timeout: std::time::Duration::from_millis(100),
+            max_partial: 64,
+        }
+    }
+}
+
+/// A partially received multi-frame message, keyed by arbitration id.
+struct PartialMsg {
+    total_len: usize,
+    buffer: Vec<u8>,
+    /// Out-of-order consecutive frames held until their prefix is contiguous.
+    pending: std::collections::BTreeMap<u8, Vec<u8>>,
+    next_expected: u8,
+    last_update: std::time::Instant,
+}
+
+/// ISO-TP style segment reassembler. First Frames (PCI `0x1`) carry the total
+/// length; Consecutive Frames (PCI `0x2`) carry a rolling 4-bit sequence
+/// counter. Single Frames (PCI `0x0`) are delivered whole.
+struct Reassembler {
+    config: ReassemblyConfig,
+    partials: HashMap<u32, PartialMsg>,
+}
+
+impl Reassembler {
+    fn new(config: ReassemblyConfig) -> Self {
+        Self {
+            config,
+            partials: HashMap::new(),
+        }
+    }
+
+    /// Feed a segment frame. Returns the assembled payload once a message
+    /// completes, or `None` while more segments are awaited.
+    fn ingest(&mut self, can_id: u32, data: &[u8]) -> Option<Vec<u8>> {
+        self.evict_stale();
+        if data.is_empty() {
+            return None;
+        }
+        let now = std::time::Instant::now();
+        match data[0] >> 4 {
+            // Single Frame: low nibble is the length, payload follows.
+            0x0 => {
+                let len = (data[0] & 0x0F) as usize;
+                let end = (1 + len).min(data.len());
+                Some(data[1..end].to_vec())
+            }
+            // First Frame: 12-bit total length, 6 payload bytes.
+            0x1 if data.len() >= 2 => {
+                if self.partials.len() >= self.config.max_partial {
+                    return None;
+                }
+                let total_len = (((data[0] & 0x0F) as usize) << 8) | data[1] as usize;
+                let buffer = data[2..].to_vec();
+                let mut partial = PartialMsg {
+                    total_len,
+                    buffer,
+                    pending: std::collections::BTreeMap::new(),
+                    next_expected: 1,
+                    last_update: now,
+                };
+                Self::try_complete(&mut partial).or_else(|| {
+                    self.partials.insert(can_id, partial);
+                    None
+                })
+            }
+            // Consecutive Frame: low nibble is the rolling sequence number.
+            0x2 => {
+                let seq = data[0] & 0x0F;
+                let partial = self.partials.get_mut(&can_id)?;
+                partial.last_update = now;
+                partial.pending.insert(seq, data[1..].to_vec());
+                // Append any sequence numbers whose prefix is now contiguous.
+                while let Some(segment) = partial.pending.remove(&partial.next_expected) {
+                    partial.buffer.extend_from_slice(&segment);
+                    partial.next_expected = (partial.next_expected + 1) & 0x0F;
+                }
+                if let Some(done) = Self::try_complete(partial) {
+                    self.partials.remove(&can_id);
+                    Some(done)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Truncate to `total_len` and return the payload when fully received.
+    fn try_complete(partial: &mut PartialMsg) -> Option<Vec<u8>> {
+        if partial.buffer.len() >= partial.total_len {
+            partial.buffer.truncate(partial.total_len);
+            Some(std::mem::take(&mut partial.buffer))
+        } else {
+            None
+        }
+    }
+
+    /// Drop partial messages that have been idle past the configured timeout.
+    fn evict_stale(&mut self) {
+        let timeout = self.config.timeout;
+        let now = std::time::Instant::now();
+        self.partials
+            .retain(|_, partial| now.duration_since(partial.last_update) <= timeout);
+    }
+}
+
+/// Direction a captured frame travelled relative to the collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameDirection {
+    /// Frame transmitted by the collection (`send_packet`).
+    Tx,
+    /// Frame received by the collection (`dispatch_frame`).
+    Rx,
+}
+
+/// A single captured frame, stamped with a monotonic offset from the start of
+/// the capture.
+#[derive(Debug, Clone)]
+pub struct CapturedFrame {
+    pub timestamp: std::time::Duration,
+    pub direction: FrameDirection,
+    pub can_id: u32,
+    pub data: Vec<u8>,
+}
+
+/// Hook invoked for every frame passing through the collection. Installed with
+/// [`CANDeviceCollection::set_tracer`]; implementors capture traffic for
+/// offline debugging or deterministic regression tests.
+pub trait FrameTracer: Send + Sync {
+    /// Record one frame in the given direction.
+    fn record(&self, direction: FrameDirection, can_id: u32, data: &[u8]);
+}
+
+/// In-memory [`FrameTracer`] that timestamps frames relative to its creation.
+pub struct CaptureLog {
+    start: std::time::Instant,
+    frames: Mutex<Vec<CapturedFrame>>,
+}
+
+impl CaptureLog {
+    /// Start a new capture with the clock zeroed to now.
+    pub fn new() -> Self {
+        Self {
+            start: std::time::Instant::now(),
+            frames: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Snapshot the frames captured so far.
+    pub fn frames(&self) -> Vec<CapturedFrame> {
+        self.frames.lock().unwrap().clone()
+    }
+
+    /// Render the capture as candump-style text (`(secs) id#hexdata`).
+    pub fn to_candump(&self) -> String {
+        let mut out = String::new();
+        for frame in self.frames.lock().unwrap().iter() {
+            let hex: String = frame.data.iter().map(|b| format!("{:02X}", b)).collect();
+            out.push_str(&format!(
+                "({:.6}) {:03X}#{}\n",
+                frame.timestamp.as_secs_f64(),
+                frame.can_id,
+                hex
+            ));
+        }
+        out
+    }
+}
+
+impl Default for CaptureLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrameTracer for CaptureLog {
+    fn record(&self, direction: FrameDirection, can_id: u32, data: &[u8]) {
+        self.frames.lock().unwrap().push(CapturedFrame {
+            timestamp: self.start.elapsed(),
+            direction,
+            can_id,
+            data: data.to_vec(),
+        });
+    }
+}
+
+/// Re-injects a recorded capture, honoring the original inter-frame timing.
+pub struct Replayer {
+    frames: Vec<CapturedFrame>,
+}
+
+impl Replayer {
+    /// Build a replayer from a captured frame log.
+    pub fn from_frames(frames: Vec<CapturedFrame>) -> Self {
+        Self { frames }
+    }
+
+    /// Replay every `Tx` frame through `send`, sleeping to reproduce the gap
+    /// between consecutive frames. `Rx` frames are skipped.
+    pub fn replay(&self, mut send: impl FnMut(u32, &[u8]) -> Result<()>) -> Result<()> {
+        let mut prev: Option<std::time::Duration> = None;
+        for frame in &self.frames {
+            if let Some(prev) = prev {
+                if frame.timestamp > prev {
+                    std::thread::sleep(frame.timestamp - prev);
+                }
+            }
+            prev = Some(frame.timestamp);
+            if frame.direction == FrameDirection::Tx {
+                send(frame.can_id, &frame.data)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sentinel repeat count meaning "transmit forever until stopped".
+const CYCLIC_UNBOUNDED: u64 = u64::MAX;
+
+/// Mutable state shared between a running cyclic-send thread and its
+/// [`TaskHandle`]. The thread reads `data`/`running`/`remaining` every cycle;
+/// the handle swaps them under the same atomics so a live control stream can be
+/// retargeted without being torn down.
+struct CyclicShared {
+    /// Payload flushed on the next cycle; swapped atomically by `modify_data`.
+    data: Mutex<Vec<u8>>,
+    /// Whether the task is currently transmitting (`false` = paused).
+    running: AtomicBool,
+    /// Set by [`CANDeviceCollection::close`] / `Drop` to make the thread exit.
+    shutdown: AtomicBool,
+    /// Remaining cycles, or [`CYCLIC_UNBOUNDED`] for an infinite task.
+    remaining: AtomicU64,
+    /// Cycle count a `start()` re-arms `remaining` to.
+    initial: u64,
+}
+
+/// Handle to a cyclic-send task running on a [`CANDeviceCollection`].
+///
+/// Modelled on python-can's broadcast-manager tasks: the payload can be
+/// retargeted in place with [`modify_data`](Self::modify_data), and the stream
+/// can be paused and re-armed with [`stop`](Self::stop) / [`start`](Self::start).
+pub struct TaskHandle {
+    shared: Arc<CyclicShared>,
+}
+
+impl TaskHandle {
+    /// Swap the payload sent on subsequent cycles. The running thread picks up
+    /// the new torque/position setpoint on its next wake-up.
+    pub fn modify_data(&self, new_data: Vec<u8>) {
+        *self.shared.data.lock().unwrap() = new_data;
+    }
+
+    /// Pause transmission without joining the thread; [`start`](Self::start)
+    /// resumes it.
+    pub fn stop(&self) {
+        self.shared.running.store(false, Ordering::Release);
+    }
+
+    /// (Re)start a paused or finished task, re-arming a finite task's repeat
+    /// count.
+    pub fn start(&self) {
+        self.shared
+            .remaining
+            .store(self.shared.initial, Ordering::Release);
+        self.shared.running.store(true, Ordering::Release);
+    }
+}
+
+/// A registered cyclic task: its shared state and the worker thread driving it.
+struct CyclicTask {
+    shared: Arc<CyclicShared>,
+    handle: Option<JoinHandle<()>>,
+}
+
 /// Collection of CAN devices with frame dispatch.
 pub struct CANDeviceCollection {
     devices: Arc<Mutex<HashMap<u32, Arc<Mutex<MotorDeviceCan>>>>>,
     socket: Arc<Mutex<CANSocket>>,
+    subscribers: Arc<Mutex<SubscriberTable>>,
+    reassembler: Arc<Mutex<Option<Reassembler>>>,
+    tracer: Arc<Mutex<Option<Arc<dyn FrameTracer>>>>,
+    cyclic_tasks: Arc<Mutex<Vec<CyclicTask>>>,
+    /// Software receive filters, mirrored to the kernel via `CAN_RAW_FILTER`
+    /// when the backend supports it. An empty set accepts every frame.
+    filters: Arc<Mutex<Vec<(u32, u32)>>>,
+    /// Set once a caller has explicitly installed filters via
+    /// [`set_filters`](Self::set_filters). Once set,
+    /// [`refresh_auto_filters`](Self::refresh_auto_filters) leaves the
+    /// filter set alone on device register/unregister and subscriber
+    /// changes, instead of silently overwriting the caller's explicit
+    /// choice with the auto-derived recv-id set.
+    user_managed_filters: Arc<AtomicBool>,
+    /// When `Some`, `send_packet`/`send_batch` accumulate frames here instead of
+    /// transmitting, so a whole-arm update can be coalesced into one
+    /// `sendmmsg` at [`flush_batch`](Self::flush_batch).
+    batch: Arc<Mutex<Option<Vec<(u32, Vec<u8>)>>>>,
 }
 
 impl CANDeviceCollection {
@@ -20,6 +434,13 @@ impl CANDeviceCollection {
         Self {
             devices: Arc::new(Mutex::new(HashMap::new())),
             socket: Arc::new(Mutex::new(socket)),
+            subscribers: Arc::new(Mutex::new(SubscriberTable::new())),
+            reassembler: Arc::new(Mutex::new(None)),
+            tracer: Arc::new(Mutex::new(None)),
+            cyclic_tasks: Arc::new(Mutex::new(Vec::new())),
+            filters: Arc::new(Mutex::new(Vec::new())),
+            user_managed_filters: Arc::new(AtomicBool::new(false)),
+            batch: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -28,6 +449,13 @@ impl CANDeviceCollection {
         Self {
             devices: Arc::new(Mutex::new(HashMap::new())),
             socket,
+            subscribers: Arc::new(Mutex::new(SubscriberTable::new())),
+            reassembler: Arc::new(Mutex::new(None)),
+            tracer: Arc::new(Mutex::new(None)),
+            cyclic_tasks: Arc::new(Mutex::new(Vec::new())),
+            filters: Arc::new(Mutex::new(Vec::new())),
+            user_managed_filters: Arc::new(AtomicBool::new(false)),
+            batch: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -39,17 +467,99 @@ impl CANDeviceCollection {
             .lock()
             .unwrap()
             .insert(recv_id, Arc::new(Mutex::new(device_clone)));
+        self.refresh_auto_filters();
     }
 
     /// Register device from Arc.
     pub fn register_device_internal(&self, device: Arc<Mutex<MotorDeviceCan>>) {
         let recv_id = device.lock().unwrap().recv_can_id();
         self.devices.lock().unwrap().insert(recv_id, device);
+        self.refresh_auto_filters();
     }
 
     /// Unregister a device from the collection.
     pub fn unregister_device(&self, recv_can_id: u32) {
         self.devices.lock().unwrap().remove(&recv_can_id);
+        self.refresh_auto_filters();
+    }
+
+    /// Install receive filters, mirroring python-can's `can_filters`.
+    ///
+    /// Each `(can_id, can_mask)` pair admits a frame when
+    /// `frame_id & can_mask == can_id & can_mask`. The set is pushed to the
+    /// kernel via `CAN_RAW_FILTER` when the backend supports it and retained
+    /// for the software fallback in [`dispatch_borrowed`](Self::dispatch_borrowed).
+    /// An empty set clears filtering and accepts every frame.
+    ///
+    /// Once called, this collection's own recv-id auto-filtering (installed
+    /// on device register/unregister and subscriber changes) steps aside
+    /// permanently in favor of the caller's explicit set — see
+    /// [`refresh_auto_filters`](Self::refresh_auto_filters).
+    pub fn set_filters(&self, filters: Vec<(u32, u32)>) -> Result<()> {
+        // A zero-length kernel filter drops everything; `(0, 0)` admits all.
+        let kernel: &[(u32, u32)] = if filters.is_empty() { &[(0, 0)] } else { &filters };
+        self.socket.lock().unwrap().set_filters(kernel)?;
+        *self.filters.lock().unwrap() = filters;
+        self.user_managed_filters.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Rebuild the software/kernel filter set from the registered recv-IDs so
+    /// only relevant reply frames ever wake the reader.
+    ///
+    /// A no-op once the caller has explicitly installed filters via
+    /// [`set_filters`](Self::set_filters) — auto-deriving here would silently
+    /// clobber that explicit choice. Otherwise, skipped entirely while a
+    /// `Protocol::All` sniffer or a ranged [`subscribe`](Self::subscribe)
+    /// selector is active: installing a recv-id-only filter would starve it
+    /// at the kernel before the frame ever reaches
+    /// [`dispatch_borrowed_ts`](Self::dispatch_borrowed_ts)'s subscriber
+    /// fan-out, defeating the promiscuous-monitor/gateway use case
+    /// `subscribe` exists for. Exact-id subscriber ids are admitted alongside
+    /// the device recv-IDs. Kernel-install errors are swallowed here — the
+    /// software fallback still enforces the set; the kernel filter is not
+    /// installed again until the next refresh.
+    fn refresh_auto_filters(&self) {
+        if self.user_managed_filters.load(Ordering::Relaxed) {
+            return;
+        }
+
+        if self.has_wildcard_subscriber() {
+            let _ = self.socket.lock().unwrap().set_filters(&[(0, 0)]);
+            self.filters.lock().unwrap().clear();
+            return;
+        }
+
+        let mut filters: Vec<(u32, u32)> = self
+            .devices
+            .lock()
+            .unwrap()
+            .keys()
+            .map(|&recv_id| (recv_id, libc::CAN_SFF_MASK))
+            .collect();
+        filters.extend(self.subscribers.lock().unwrap().entries.iter().filter_map(
+            |(_, filter, _)| match filter {
+                Protocol::Specific { id, mask } => Some((*id, *mask)),
+                Protocol::All => None,
+            },
+        ));
+        filters.sort_unstable();
+        filters.dedup();
+        let _ = self.socket.lock().unwrap().set_filters(&filters);
+        *self.filters.lock().unwrap() = filters;
+    }
+
+    /// Whether any subscriber wants more than a single exact id: a
+    /// `Protocol::All` sniffer, or a `Protocol::Specific` selector whose mask
+    /// leaves some id bits unconstrained and so matches a range of ids.
+    fn has_wildcard_subscriber(&self) -> bool {
+        self.subscribers.lock().unwrap().entries.iter().any(|(_, filter, _)| {
+            !matches!(
+                filter,
+                Protocol::Specific { mask, .. }
+                    if *mask == libc::CAN_SFF_MASK || *mask == libc::CAN_EFF_MASK || *mask == u32::MAX
+            )
+        })
     }
 
     /// Get the number of registered devices.
@@ -82,25 +592,207 @@ impl CANDeviceCollection {
 
     /// Dispatch a received frame to the appropriate device.
     pub fn dispatch_frame(&self, can_id: u32, data: Vec<u8>) -> bool {
-        let devices = self.devices.lock().unwrap();
-        if let Some(device) = devices.get(&can_id) {
-            device.lock().unwrap().process_callback(&data);
-            true
-        } else {
-            false
+        self.dispatch_borrowed(can_id, &data)
+    }
+
+    /// Register a subscriber that observes every frame matching `filter`,
+    /// fanned out after the exact-id device has been dispatched. Returns a
+    /// handle for [`unsubscribe`](Self::unsubscribe). Enables bus monitors,
+    /// loggers, and gateways that consume ranges of ids.
+    pub fn subscribe(
+        &self,
+        filter: Protocol,
+        listener: impl Fn(u32, &[u8]) + Send + Sync + 'static,
+    ) -> u64 {
+        let id = {
+            let mut table = self.subscribers.lock().unwrap();
+            let id = table.next_id;
+            table.next_id += 1;
+            table.entries.push((id, filter, Box::new(listener)));
+            id
+        };
+        // A wildcard/range selector must not be starved by a recv-id-only
+        // kernel filter installed before it subscribed.
+        self.refresh_auto_filters();
+        id
+    }
+
+    /// Remove a previously registered subscriber. Returns `true` if a
+    /// subscriber with this handle existed.
+    pub fn unsubscribe(&self, handle: u64) -> bool {
+        let removed = {
+            let mut table = self.subscribers.lock().unwrap();
+            let before = table.entries.len();
+            table.entries.retain(|(id, _, _)| *id != handle);
+            table.entries.len() != before
+        };
+        if removed {
+            self.refresh_auto_filters();
         }
+        removed
+    }
+
+    /// Subscribe to a single receive id over a bounded MPSC channel. The
+    /// receive path pushes matching frames onto the queue and returns
+    /// immediately; the returned [`FrameReceiver`] is drained by the consumer
+    /// on its own thread. `capacity` bounds the queue and `policy` selects
+    /// what happens when it overflows.
+    pub fn subscribe_channel(
+        &self,
+        recv_can_id: u32,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> FrameReceiver {
+        self.subscribe_with_filter(
+            Protocol::Specific {
+                id: recv_can_id,
+                mask: u32::MAX,
+            },
+            capacity,
+            policy,
+        )
+    }
+
+    /// Subscribe to every frame on the bus over a bounded MPSC channel. See
+    /// [`subscribe_channel`](Self::subscribe_channel) for the queue semantics.
+    pub fn subscribe_channel_all(&self, capacity: usize, policy: OverflowPolicy) -> FrameReceiver {
+        self.subscribe_with_filter(Protocol::All, capacity, policy)
+    }
+
+    fn subscribe_with_filter(
+        &self,
+        filter: Protocol,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> FrameReceiver {
+        let inner = Arc::new(ChannelInner {
+            queue: Mutex::new(VecDeque::new()),
+            not_empty: Condvar::new(),
+            capacity: capacity.max(1),
+            policy,
+        });
+        let producer = Arc::clone(&inner);
+        self.subscribe(filter, move |can_id, data| {
+            producer.push(can_id, data.to_vec());
+        });
+        FrameReceiver { inner }
+    }
+
+    /// Enable ISO-TP segment reassembly with the given tunables. Frames fed
+    /// through [`ingest_segment`](Self::ingest_segment) are buffered per id
+    /// until a complete payload is assembled.
+    pub fn enable_reassembly(&self, config: ReassemblyConfig) {
+        *self.reassembler.lock().unwrap() = Some(Reassembler::new(config));
+    }
+
+    /// Disable reassembly and discard any in-flight partial messages.
+    pub fn disable_reassembly(&self) {
+        *self.reassembler.lock().unwrap() = None;
+    }
+
+    /// Feed a segment frame through the reassembler. When a multi-frame
+    /// message completes, it is dispatched to the matching device via
+    /// [`dispatch_borrowed`](Self::dispatch_borrowed) and the entry evicted.
+    /// Returns `true` when a complete payload was dispatched this call. If
+    /// reassembly is disabled, the frame is dispatched as-is.
+    pub fn ingest_segment(&self, can_id: u32, data: &[u8]) -> bool {
+        let assembled = {
+            let mut guard = self.reassembler.lock().unwrap();
+            match guard.as_mut() {
+                Some(reassembler) => reassembler.ingest(can_id, data),
+                None => {
+                    drop(guard);
+                    return self.dispatch_borrowed(can_id, data);
+                }
+            }
+        };
+        match assembled {
+            Some(payload) => self.dispatch_borrowed(can_id, &payload),
+            None => false,
+        }
+    }
+
+    /// Install a frame tracer that captures every `send_packet` and
+    /// `dispatch_frame` with a monotonic timestamp. Keep a clone of the
+    /// [`Arc`] (e.g. a [`CaptureLog`]) to read back the capture afterwards.
+    pub fn set_tracer(&self, tracer: Arc<dyn FrameTracer>) {
+        *self.tracer.lock().unwrap() = Some(tracer);
+    }
+
+    /// Remove any installed tracer.
+    pub fn clear_tracer(&self) {
+        *self.tracer.lock().unwrap() = None;
+    }
+
+    /// Replay a recorded capture back onto the bus, re-sending its `Tx` frames
+    /// through `send_packet` while honoring the original inter-frame timing.
+    pub fn replay(&self, replayer: &Replayer) -> Result<()> {
+        replayer.replay(|can_id, data| self.send_packet(can_id, data))
+    }
+
+    /// Dispatch a received frame from a borrowed payload, avoiding an owned
+    /// `Vec`. [`dispatch_frame`](Self::dispatch_frame) forwards to it. After
+    /// routing to the exact-id device, the frame is fanned out to every
+    /// matching subscriber.
+    pub fn dispatch_borrowed(&self, can_id: u32, data: &[u8]) -> bool {
+        self.dispatch_borrowed_ts(can_id, data, None)
+    }
+
+    /// Dispatch a borrowed frame together with its receive timestamp.
+    ///
+    /// Like [`dispatch_borrowed`](Self::dispatch_borrowed) but records
+    /// `timestamp_us` (kernel receive time, in microseconds since the Unix
+    /// epoch) on the target device so callers can measure control-loop latency.
+    pub fn dispatch_borrowed_ts(&self, can_id: u32, data: &[u8], timestamp_us: Option<u64>) -> bool {
+        // Software fallback for the filter set: on hardware-filtering backends
+        // this is already enforced by the kernel, but virtual/loopback buses
+        // rely on this check to drop frames outside the registered id set.
+        // This only gates device routing -- subscribers below (e.g. a
+        // `Protocol::All` bus monitor or a ranged gateway) still see every
+        // frame regardless of whether it matches a registered device id.
+        let passes_device_filter = {
+            let filters = self.filters.lock().unwrap();
+            filters.is_empty() || filters.iter().any(|&(id, mask)| can_id & mask == id & mask)
+        };
+
+        if let Some(tracer) = self.tracer.lock().unwrap().as_ref() {
+            tracer.record(FrameDirection::Rx, can_id, data);
+        }
+
+        let matched = passes_device_filter && {
+            let devices = self.devices.lock().unwrap();
+            if let Some(device) = devices.get(&can_id) {
+                device.lock().unwrap().process_callback_ts(data, timestamp_us);
+                true
+            } else {
+                false
+            }
+        };
+
+        let subscribers = self.subscribers.lock().unwrap();
+        for (_, filter, listener) in subscribers.entries.iter() {
+            if filter.matches(can_id) {
+                listener(can_id, data);
+            }
+        }
+
+        matched
     }
 
     /// Receive all available frames with timeout for first frame.
+    ///
+    /// Each frame is read through the timestamping path so the kernel receive
+    /// time (when [`enable_timestamps`](CANSocket::enable_timestamps) is active)
+    /// is threaded into the target device; otherwise the timestamp is `None`.
     pub fn recv_all(&self, first_timeout_us: u64) -> Result<usize> {
         let socket = self.socket.lock().unwrap();
         let mut count = 0;
 
         // Wait for first frame with specified timeout
         if socket.is_data_available(first_timeout_us)? {
-            if let Some((can_id, data)) = socket.read_raw()? {
+            if let Some((can_id, data, ts)) = socket.read_raw_ts()? {
                 drop(socket); // Release lock before dispatch
-                self.dispatch_frame(can_id, data);
+                self.dispatch_borrowed_ts(can_id, &data, ts.map(|d| d.as_micros() as u64));
                 count += 1;
 
                 // Read remaining frames with zero timeout (non-blocking)
@@ -109,9 +801,44 @@ impl CANDeviceCollection {
                     if !socket.is_data_available(0)? {
                         break;
                     }
-                    if let Some((can_id, data)) = socket.read_raw()? {
+                    if let Some((can_id, data, ts)) = socket.read_raw_ts()? {
                         drop(socket);
-                        self.dispatch_frame(can_id, data);
+                        self.dispatch_borrowed_ts(can_id, &data, ts.map(|d| d.as_micros() as u64));
+                        count += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Receive all available frames over the zero-copy token path.
+    ///
+    /// Behaves like [`recv_all`](Self::recv_all) but hands each frame to the
+    /// target device as a borrowed slice via [`RxToken::consume`], so a
+    /// high-rate local bus incurs no per-frame heap allocation. The socket
+    /// mutex is released around each dispatch exactly as in `recv_all`.
+    pub fn recv_all_borrowed(&self, first_timeout_us: u64) -> Result<usize> {
+        let socket = self.socket.lock().unwrap();
+        let mut count = 0;
+
+        if socket.is_data_available(first_timeout_us)? {
+            if let Some(token) = socket.read_token()? {
+                drop(socket);
+                token.consume(|can_id, data| self.dispatch_borrowed(can_id, data));
+                count += 1;
+
+                loop {
+                    let socket = self.socket.lock().unwrap();
+                    if !socket.is_data_available(0)? {
+                        break;
+                    }
+                    if let Some(token) = socket.read_token()? {
+                        drop(socket);
+                        token.consume(|can_id, data| self.dispatch_borrowed(can_id, data));
                         count += 1;
                     } else {
                         break;
@@ -124,10 +851,169 @@ impl CANDeviceCollection {
     }
 
     /// Send a CAN packet through the socket.
+    ///
+    /// While a batch is open (see [`begin_batch`](Self::begin_batch)) the frame
+    /// is buffered instead of transmitted, and flushed with the rest on
+    /// [`flush_batch`](Self::flush_batch).
     pub fn send_packet(&self, can_id: u32, data: &[u8]) -> Result<()> {
+        if let Some(tracer) = self.tracer.lock().unwrap().as_ref() {
+            tracer.record(FrameDirection::Tx, can_id, data);
+        }
+        if let Some(buffer) = self.batch.lock().unwrap().as_mut() {
+            buffer.push((can_id, data.to_vec()));
+            return Ok(());
+        }
         let socket = self.socket.lock().unwrap();
         socket.write_raw(can_id, data)
     }
+
+    /// Send a batch of pre-encoded frames.
+    ///
+    /// While a batch is open, the frames are appended to it. Otherwise, when
+    /// `synchronized` is set they are pushed in a single `sendmmsg` syscall
+    /// (falling back to per-frame writes under one socket lock on kernels
+    /// without `sendmmsg`), so multi-motor setpoints start together without
+    /// interleaving other transmissions. With `synchronized` clear, each frame
+    /// is sent independently like `send_packet`.
+    pub fn send_batch(&self, frames: &[(u32, [u8; 8])], synchronized: bool) -> Result<()> {
+        if let Some(tracer) = self.tracer.lock().unwrap().as_ref() {
+            for (can_id, data) in frames {
+                tracer.record(FrameDirection::Tx, *can_id, data);
+            }
+        }
+        if let Some(buffer) = self.batch.lock().unwrap().as_mut() {
+            buffer.extend(frames.iter().map(|(id, d)| (*id, d.to_vec())));
+            return Ok(());
+        }
+        if synchronized {
+            let refs: Vec<(u32, &[u8])> = frames.iter().map(|(id, d)| (*id, &d[..])).collect();
+            self.socket.lock().unwrap().write_raw_batch(&refs)
+        } else {
+            let socket = self.socket.lock().unwrap();
+            for (can_id, data) in frames {
+                socket.write_raw(*can_id, data)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Open a transmit batch: subsequent `send_packet`/`send_batch` calls
+    /// accumulate frames instead of transmitting, until [`flush_batch`](Self::flush_batch).
+    ///
+    /// Lets the `*_control_all` helpers coalesce a whole-arm update into a
+    /// single `sendmmsg`, cutting per-frame syscall overhead. Calling it again
+    /// while a batch is open keeps the frames already accumulated.
+    pub fn begin_batch(&self) {
+        let mut batch = self.batch.lock().unwrap();
+        if batch.is_none() {
+            *batch = Some(Vec::new());
+        }
+    }
+
+    /// Close the current batch and transmit every accumulated frame in one
+    /// `sendmmsg` syscall (with the per-frame fallback). A no-op when no batch
+    /// is open.
+    pub fn flush_batch(&self) -> Result<()> {
+        let frames = self.batch.lock().unwrap().take();
+        if let Some(frames) = frames {
+            let refs: Vec<(u32, &[u8])> = frames.iter().map(|(id, d)| (*id, &d[..])).collect();
+            self.socket.lock().unwrap().write_raw_batch(&refs)?;
+        }
+        Ok(())
+    }
+
+    /// Register a background task that retransmits `data` to `can_id` every
+    /// `period_us` microseconds on a dedicated thread.
+    ///
+    /// With `duration_us` set, the task stops once that window elapses (rounded
+    /// up to a whole number of cycles); otherwise it runs until
+    /// [`TaskHandle::stop`] or [`close`](Self::close). The worker uses an
+    /// absolute monotonic deadline recomputed each cycle so scheduling jitter
+    /// does not accumulate into drift. Mirrors python-can's broadcast manager
+    /// and keeps high-rate control streams off the GIL.
+    pub fn add_cyclic(
+        &self,
+        can_id: u32,
+        data: Vec<u8>,
+        period_us: u64,
+        duration_us: Option<u64>,
+    ) -> TaskHandle {
+        let period_us = period_us.max(1);
+        let period = Duration::from_micros(period_us);
+        let remaining = match duration_us {
+            Some(us) => (us + period_us - 1) / period_us,
+            None => CYCLIC_UNBOUNDED,
+        };
+        let shared = Arc::new(CyclicShared {
+            data: Mutex::new(data),
+            running: AtomicBool::new(true),
+            shutdown: AtomicBool::new(false),
+            remaining: AtomicU64::new(remaining),
+            initial: remaining,
+        });
+
+        let worker = Arc::clone(&shared);
+        let socket = Arc::clone(&self.socket);
+        let tracer = Arc::clone(&self.tracer);
+        let handle = std::thread::spawn(move || {
+            let mut next = Instant::now();
+            while !worker.shutdown.load(Ordering::Acquire) {
+                if worker.running.load(Ordering::Acquire) {
+                    let count = worker.remaining.load(Ordering::Acquire);
+                    if count == 0 {
+                        worker.running.store(false, Ordering::Release);
+                    } else {
+                        let payload = worker.data.lock().unwrap().clone();
+                        if let Some(tracer) = tracer.lock().unwrap().as_ref() {
+                            tracer.record(FrameDirection::Tx, can_id, &payload);
+                        }
+                        let _ = socket.lock().unwrap().write_raw(can_id, &payload);
+                        if count != CYCLIC_UNBOUNDED {
+                            worker.remaining.store(count - 1, Ordering::Release);
+                        }
+                    }
+                }
+
+                next += period;
+                let now = Instant::now();
+                if next > now {
+                    std::thread::sleep(next - now);
+                } else {
+                    // Fell behind (paused or slow bus): re-baseline so the next
+                    // cycle does not fire a burst to "catch up".
+                    next = now;
+                }
+            }
+        });
+
+        let task = CyclicTask {
+            shared: Arc::clone(&shared),
+            handle: Some(handle),
+        };
+        self.cyclic_tasks.lock().unwrap().push(task);
+        TaskHandle { shared }
+    }
+
+    /// Stop and join every cyclic-send thread spawned by
+    /// [`add_cyclic`](Self::add_cyclic). Idempotent.
+    pub fn close(&self) {
+        let mut tasks = self.cyclic_tasks.lock().unwrap();
+        for task in tasks.iter() {
+            task.shared.shutdown.store(true, Ordering::Release);
+        }
+        for task in tasks.iter_mut() {
+            if let Some(handle) = task.handle.take() {
+                let _ = handle.join();
+            }
+        }
+        tasks.clear();
+    }
+}
+
+impl Drop for CANDeviceCollection {
+    fn drop(&mut self) {
+        self.close();
+    }
 }
 
 /// Collection of CAN devices with frame dispatch, supporting both local and remote sockets.
@@ -135,6 +1021,9 @@ impl CANDeviceCollection {
 pub struct AnyCANDeviceCollection {
     devices: Arc<Mutex<HashMap<u32, Arc<Mutex<MotorDeviceCan>>>>>,
     socket: Arc<Mutex<super::socket::AnyCANSocket>>,
+    subscribers: Arc<Mutex<SubscriberTable>>,
+    reassembler: Arc<Mutex<Option<Reassembler>>>,
+    tracer: Arc<Mutex<Option<Arc<dyn FrameTracer>>>>,
 }
 
 #[cfg(feature = "remote")]
@@ -144,6 +1033,9 @@ impl AnyCANDeviceCollection {
         Self {
             devices: Arc::new(Mutex::new(HashMap::new())),
             socket: Arc::new(Mutex::new(socket)),
+            subscribers: Arc::new(Mutex::new(SubscriberTable::new())),
+            reassembler: Arc::new(Mutex::new(None)),
+            tracer: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -152,6 +1044,9 @@ impl AnyCANDeviceCollection {
         Self {
             devices: Arc::new(Mutex::new(HashMap::new())),
             socket,
+            subscribers: Arc::new(Mutex::new(SubscriberTable::new())),
+            reassembler: Arc::new(Mutex::new(None)),
+            tracer: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -201,15 +1096,161 @@ impl AnyCANDeviceCollection {
 
     /// Dispatch a received frame to the appropriate device.
     pub fn dispatch_frame(&self, can_id: u32, data: Vec<u8>) -> bool {
-        let devices = self.devices.lock().unwrap();
-        if let Some(device) = devices.get(&can_id) {
-            device.lock().unwrap().process_callback(&data);
-            true
-        } else {
-            false
+        self.dispatch_borrowed(can_id, &data)
+    }
+
+    /// Register a subscriber that observes every frame matching `filter`,
+    /// fanned out after the exact-id device has been dispatched. Returns a
+    /// handle for [`unsubscribe`](Self::unsubscribe). Enables bus monitors,
+    /// loggers, and gateways that consume ranges of ids.
+    pub fn subscribe(
+        &self,
+        filter: Protocol,
+        listener: impl Fn(u32, &[u8]) + Send + Sync + 'static,
+    ) -> u64 {
+        let mut table = self.subscribers.lock().unwrap();
+        let id = table.next_id;
+        table.next_id += 1;
+        table.entries.push((id, filter, Box::new(listener)));
+        id
+    }
+
+    /// Remove a previously registered subscriber. Returns `true` if a
+    /// subscriber with this handle existed.
+    pub fn unsubscribe(&self, handle: u64) -> bool {
+        let mut table = self.subscribers.lock().unwrap();
+        let before = table.entries.len();
+        table.entries.retain(|(id, _, _)| *id != handle);
+        table.entries.len() != before
+    }
+
+    /// Subscribe to a single receive id over a bounded MPSC channel. The
+    /// receive path pushes matching frames onto the queue and returns
+    /// immediately; the returned [`FrameReceiver`] is drained by the consumer
+    /// on its own thread. `capacity` bounds the queue and `policy` selects
+    /// what happens when it overflows.
+    pub fn subscribe_channel(
+        &self,
+        recv_can_id: u32,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> FrameReceiver {
+        self.subscribe_with_filter(
+            Protocol::Specific {
+                id: recv_can_id,
+                mask: u32::MAX,
+            },
+            capacity,
+            policy,
+        )
+    }
+
+    /// Subscribe to every frame on the bus over a bounded MPSC channel. See
+    /// [`subscribe_channel`](Self::subscribe_channel) for the queue semantics.
+    pub fn subscribe_channel_all(&self, capacity: usize, policy: OverflowPolicy) -> FrameReceiver {
+        self.subscribe_with_filter(Protocol::All, capacity, policy)
+    }
+
+    fn subscribe_with_filter(
+        &self,
+        filter: Protocol,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> FrameReceiver {
+        let inner = Arc::new(ChannelInner {
+            queue: Mutex::new(VecDeque::new()),
+            not_empty: Condvar::new(),
+            capacity: capacity.max(1),
+            policy,
+        });
+        let producer = Arc::clone(&inner);
+        self.subscribe(filter, move |can_id, data| {
+            producer.push(can_id, data.to_vec());
+        });
+        FrameReceiver { inner }
+    }
+
+    /// Enable ISO-TP segment reassembly with the given tunables. Frames fed
+    /// through [`ingest_segment`](Self::ingest_segment) are buffered per id
+    /// until a complete payload is assembled.
+    pub fn enable_reassembly(&self, config: ReassemblyConfig) {
+        *self.reassembler.lock().unwrap() = Some(Reassembler::new(config));
+    }
+
+    /// Disable reassembly and discard any in-flight partial messages.
+    pub fn disable_reassembly(&self) {
+        *self.reassembler.lock().unwrap() = None;
+    }
+
+    /// Feed a segment frame through the reassembler. When a multi-frame
+    /// message completes, it is dispatched to the matching device via
+    /// [`dispatch_borrowed`](Self::dispatch_borrowed) and the entry evicted.
+    /// Returns `true` when a complete payload was dispatched this call. If
+    /// reassembly is disabled, the frame is dispatched as-is.
+    pub fn ingest_segment(&self, can_id: u32, data: &[u8]) -> bool {
+        let assembled = {
+            let mut guard = self.reassembler.lock().unwrap();
+            match guard.as_mut() {
+                Some(reassembler) => reassembler.ingest(can_id, data),
+                None => {
+                    drop(guard);
+                    return self.dispatch_borrowed(can_id, data);
+                }
+            }
+        };
+        match assembled {
+            Some(payload) => self.dispatch_borrowed(can_id, &payload),
+            None => false,
         }
     }
 
+    /// Install a frame tracer that captures every `send_packet` and
+    /// `dispatch_frame` with a monotonic timestamp. Keep a clone of the
+    /// [`Arc`] (e.g. a [`CaptureLog`]) to read back the capture afterwards.
+    pub fn set_tracer(&self, tracer: Arc<dyn FrameTracer>) {
+        *self.tracer.lock().unwrap() = Some(tracer);
+    }
+
+    /// Remove any installed tracer.
+    pub fn clear_tracer(&self) {
+        *self.tracer.lock().unwrap() = None;
+    }
+
+    /// Replay a recorded capture back onto the bus, re-sending its `Tx` frames
+    /// through `send_packet` while honoring the original inter-frame timing.
+    pub fn replay(&self, replayer: &Replayer) -> Result<()> {
+        replayer.replay(|can_id, data| self.send_packet(can_id, data))
+    }
+
+    /// Dispatch a received frame from a borrowed payload, avoiding an owned
+    /// `Vec`. [`dispatch_frame`](Self::dispatch_frame) forwards to it. After
+    /// routing to the exact-id device, the frame is fanned out to every
+    /// matching subscriber.
+    pub fn dispatch_borrowed(&self, can_id: u32, data: &[u8]) -> bool {
+        if let Some(tracer) = self.tracer.lock().unwrap().as_ref() {
+            tracer.record(FrameDirection::Rx, can_id, data);
+        }
+
+        let matched = {
+            let devices = self.devices.lock().unwrap();
+            if let Some(device) = devices.get(&can_id) {
+                device.lock().unwrap().process_callback(data);
+                true
+            } else {
+                false
+            }
+        };
+
+        let subscribers = self.subscribers.lock().unwrap();
+        for (_, filter, listener) in subscribers.entries.iter() {
+            if filter.matches(can_id) {
+                listener(can_id, data);
+            }
+        }
+
+        matched
+    }
+
     /// Receive all available frames with timeout for first frame.
     pub fn recv_all(&self, first_timeout_us: u64) -> Result<usize> {
         let socket = self.socket.lock().unwrap();
@@ -242,9 +1283,68 @@ impl AnyCANDeviceCollection {
         Ok(count)
     }
 
+    /// Receive all available frames over the zero-copy token path.
+    ///
+    /// Behaves like [`recv_all`](Self::recv_all) but hands each frame to the
+    /// target device as a borrowed slice via [`RxToken::consume`], so a
+    /// high-rate local bus incurs no per-frame heap allocation. The socket
+    /// mutex is released around each dispatch exactly as in `recv_all`.
+    pub fn recv_all_borrowed(&self, first_timeout_us: u64) -> Result<usize> {
+        let socket = self.socket.lock().unwrap();
+        let mut count = 0;
+
+        if socket.is_data_available(first_timeout_us)? {
+            if let Some(token) = socket.read_token()? {
+                drop(socket);
+                token.consume(|can_id, data| self.dispatch_borrowed(can_id, data));
+                count += 1;
+
+                loop {
+                    let socket = self.socket.lock().unwrap();
+                    if !socket.is_data_available(0)? {
+                        break;
+                    }
+                    if let Some(token) = socket.read_token()? {
+                        drop(socket);
+                        token.consume(|can_id, data| self.dispatch_borrowed(can_id, data));
+                        count += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
     /// Send a CAN packet through the socket.
     pub fn send_packet(&self, can_id: u32, data: &[u8]) -> Result<()> {
+        if let Some(tracer) = self.tracer.lock().unwrap().as_ref() {
+            tracer.record(FrameDirection::Tx, can_id, data);
+        }
         let socket = self.socket.lock().unwrap();
         socket.write_raw(can_id, data)
     }
+
+    /// Send a batch of pre-encoded frames.
+    ///
+    /// When `synchronized` is set, the socket lock is taken once and every
+    /// frame is flushed back-to-back, so multi-motor setpoints start together
+    /// without interleaving other transmissions. Otherwise each frame is sent
+    /// independently like `send_packet`.
+    pub fn send_batch(&self, frames: &[(u32, [u8; 8])], synchronized: bool) -> Result<()> {
+        if synchronized {
+            let socket = self.socket.lock().unwrap();
+            for (can_id, data) in frames {
+                socket.write_raw(*can_id, data)?;
+            }
+            Ok(())
+        } else {
+            for (can_id, data) in frames {
+                self.send_packet(*can_id, data)?;
+            }
+            Ok(())
+        }
+    }
 }
@@ -2,13 +2,26 @@
 
 pub mod device;
 pub mod device_collection;
+#[cfg(feature = "async")]
+pub mod reactor;
+pub mod simulated;
 pub mod socket;
+pub mod virtual_bus;
 
 pub use device::*;
 pub use device_collection::CANDeviceCollection;
+pub use device_collection::{
+    CaptureLog, CapturedFrame, FrameDirection, FrameReceiver, FrameTracer, OverflowPolicy,
+    Protocol, ReassemblyConfig, Replayer, TaskHandle,
+};
+pub use socket::BusState;
 pub use socket::CANSocket;
+pub use socket::CanBusError;
 pub use socket::CanFdFrame;
 pub use socket::CanFrame;
+pub use socket::RxToken;
+pub use simulated::SimulatedCanSocket;
+pub use virtual_bus::VirtualCanBackend;
 
 #[cfg(feature = "remote")]
 pub use device_collection::AnyCANDeviceCollection;
@@ -0,0 +1,116 @@
+//! Async frame-reception reactor for CAN device collections.
+//!
+//! `recv_all` ties up a thread per bus: it holds the socket mutex and spins on
+//! `is_data_available`. The reactor instead registers the socket fd with an
+//! async runtime and awaits readiness, so a single executor can supervise many
+//! collections concurrently without dedicating an OS thread to each bus.
+
+use tokio::io::unix::AsyncFd;
+use tokio::io::Interest;
+
+use super::device_collection::CANDeviceCollection;
+#[cfg(feature = "remote")]
+use super::device_collection::AnyCANDeviceCollection;
+use crate::error::{OpenArmError, Result};
+
+/// Newtype wrapper so a bare `RawFd` can be registered with [`AsyncFd`]
+/// without taking ownership of (and closing) the socket's descriptor.
+struct BorrowedCanFd(i32);
+
+impl std::os::unix::io::AsRawFd for BorrowedCanFd {
+    fn as_raw_fd(&self) -> i32 {
+        self.0
+    }
+}
+
+impl CANDeviceCollection {
+    /// Run the collection's receive loop on the current async runtime.
+    ///
+    /// Awaits read-readiness on the socket fd, drains every frame currently
+    /// available, and dispatches each through [`dispatch_frame`]. Never holds
+    /// the socket mutex across an await point, so other tasks sharing the
+    /// socket make progress. Runs until the socket closes or an I/O error
+    /// other than `WouldBlock` is returned.
+    ///
+    /// [`dispatch_frame`]: CANDeviceCollection::dispatch_frame
+    pub async fn run(&self) -> Result<()> {
+        let fd = self
+            .socket()
+            .lock()
+            .unwrap()
+            .raw_fd()
+            .ok_or(OpenArmError::SocketNotOpen)?;
+        let async_fd = AsyncFd::with_interest(BorrowedCanFd(fd), Interest::READABLE)
+            .map_err(OpenArmError::IoError)?;
+
+        loop {
+            let mut guard = async_fd.readable().await.map_err(OpenArmError::IoError)?;
+
+            // Drain everything the readiness event made available.
+            let mut drained = 0;
+            loop {
+                let frame = {
+                    let socket = self.socket();
+                    let socket = socket.lock().unwrap();
+                    socket.read_raw()?
+                };
+                match frame {
+                    Some((can_id, data)) => {
+                        self.dispatch_frame(can_id, data);
+                        drained += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            // No frame left: the fd would block, so clear readiness and await
+            // the next event.
+            if drained == 0 {
+                guard.clear_ready();
+            }
+        }
+    }
+}
+
+#[cfg(feature = "remote")]
+impl AnyCANDeviceCollection {
+    /// Run the collection's receive loop on the current async runtime.
+    ///
+    /// Only local sockets expose a pollable fd; remote (xoq) sockets have no
+    /// descriptor to register, so this returns [`OpenArmError::SocketNotOpen`]
+    /// for a remote collection. See [`CANDeviceCollection::run`] for details.
+    pub async fn run(&self) -> Result<()> {
+        let fd = self
+            .socket()
+            .lock()
+            .unwrap()
+            .raw_fd()
+            .ok_or(OpenArmError::SocketNotOpen)?;
+        let async_fd = AsyncFd::with_interest(BorrowedCanFd(fd), Interest::READABLE)
+            .map_err(OpenArmError::IoError)?;
+
+        loop {
+            let mut guard = async_fd.readable().await.map_err(OpenArmError::IoError)?;
+
+            let mut drained = 0;
+            loop {
+                let frame = {
+                    let socket = self.socket();
+                    let socket = socket.lock().unwrap();
+                    socket.read_raw()?
+                };
+                match frame {
+                    Some((can_id, data)) => {
+                        self.dispatch_frame(can_id, data);
+                        drained += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            if drained == 0 {
+                guard.clear_ready();
+            }
+        }
+    }
+}
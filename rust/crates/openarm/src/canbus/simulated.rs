@@ -0,0 +1,224 @@
+//! Software-simulated CAN socket for hardware-free operation.
+//!
+//! [`SimulatedCanSocket`] plays the role of a whole CAN bus: it models each
+//! registered Damiao motor as a simple first-order integrator, interprets the
+//! command frames written to it, and synthesizes plausible feedback frames that
+//! [`recv_all`](crate::canbus::AnyCANDeviceCollection::recv_all) can read back.
+//! Together with the [`Simulated`](crate::canbus::AnyCANSocket::Simulated)
+//! backend this lets an [`OpenArm`](crate::components::OpenArm)-style stack be
+//! constructed and driven in CI or on non-Linux machines without a SocketCAN
+//! interface or a remote xoq server.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::damiao_motor::{LimitParam, Motor, MotorType};
+use crate::error::Result;
+
+/// Broadcast arbitration id used by Damiao refresh (state-request) frames.
+const REFRESH_CAN_ID: u32 = 0x7FF;
+
+/// Per-motor kinematic state held by the simulated bus.
+struct SimMotor {
+    motor_type: MotorType,
+    recv_can_id: u32,
+    position: f64,
+    velocity: f64,
+    torque: f64,
+    enabled: bool,
+    last_update: Option<Instant>,
+}
+
+/// Shared mutable state of the simulated bus.
+struct SimBus {
+    /// Registered motors keyed by their send (command) CAN id.
+    motors: HashMap<u32, SimMotor>,
+    /// Synthesized feedback frames awaiting a read.
+    pending: VecDeque<(u32, Vec<u8>)>,
+}
+
+/// A simulated CAN socket that models registered motors and feeds back state.
+#[derive(Clone)]
+pub struct SimulatedCanSocket {
+    bus: Arc<Mutex<SimBus>>,
+}
+
+impl Default for SimulatedCanSocket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SimulatedCanSocket {
+    /// Create an empty simulated bus with no motors registered.
+    pub fn new() -> Self {
+        Self {
+            bus: Arc::new(Mutex::new(SimBus {
+                motors: HashMap::new(),
+                pending: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Register a motor so commands to its send id are simulated and feedback
+    /// frames are synthesized on its recv id. Re-registering the same send id
+    /// resets its kinematic state.
+    pub fn register_motor(&self, motor: &Motor) {
+        let mut bus = self.bus.lock().unwrap();
+        bus.motors.insert(
+            motor.send_can_id(),
+            SimMotor {
+                motor_type: motor.motor_type(),
+                recv_can_id: motor.recv_can_id(),
+                position: 0.0,
+                velocity: 0.0,
+                torque: 0.0,
+                enabled: false,
+                last_update: None,
+            },
+        );
+    }
+
+    /// Always open.
+    pub fn is_open(&self) -> bool {
+        true
+    }
+
+    /// Interpret a command frame and queue the resulting feedback frame.
+    pub fn write_raw(&self, can_id: u32, data: &[u8]) -> Result<()> {
+        if data.len() < 8 {
+            return Ok(());
+        }
+        let mut bus = self.bus.lock().unwrap();
+
+        // Refresh (state request): broadcast id with a target send id in the
+        // first two bytes; just emit the current state.
+        if can_id == REFRESH_CAN_ID && data[2] == 0xCC {
+            let target = (data[0] as u32) | ((data[1] as u32) << 8);
+            if let Some(frame) = bus.motors.get(&target).map(synth_feedback) {
+                bus.pending.push_back(frame);
+            }
+            return Ok(());
+        }
+
+        let Some(motor) = bus.motors.get_mut(&can_id) else {
+            return Ok(());
+        };
+
+        // Special command frames are `0xFF * 7` followed by a type byte.
+        if data[..7] == [0xFF; 7] {
+            match data[7] {
+                0xFC => motor.enabled = true,
+                0xFD => motor.enabled = false,
+                0xFE => {
+                    motor.position = 0.0;
+                    motor.velocity = 0.0;
+                    motor.torque = 0.0;
+                    motor.last_update = None;
+                }
+                _ => {}
+            }
+            let frame = synth_feedback(motor);
+            bus.pending.push_back(frame);
+            return Ok(());
+        }
+
+        step_mit(motor, data);
+        let frame = synth_feedback(motor);
+        bus.pending.push_back(frame);
+        Ok(())
+    }
+
+    /// Pop the next queued feedback frame, or `None` when the queue is empty.
+    pub fn read_raw(&self) -> Result<Option<(u32, Vec<u8>)>> {
+        Ok(self.bus.lock().unwrap().pending.pop_front())
+    }
+
+    /// Whether a feedback frame is waiting to be read.
+    pub fn is_data_available(&self, _timeout_us: u64) -> Result<bool> {
+        Ok(!self.bus.lock().unwrap().pending.is_empty())
+    }
+
+    /// Drain up to `max` queued feedback frames.
+    pub fn read_raw_batch(&self, max: usize) -> Result<Vec<(u32, Vec<u8>)>> {
+        let mut bus = self.bus.lock().unwrap();
+        let mut out = Vec::new();
+        while out.len() < max {
+            match bus.pending.pop_front() {
+                Some(frame) => out.push(frame),
+                None => break,
+            }
+        }
+        Ok(out)
+    }
+
+    /// Interpret a batch of command frames in order.
+    pub fn write_raw_batch(&self, frames: &[(u32, &[u8])]) -> Result<()> {
+        for &(can_id, data) in frames {
+            self.write_raw(can_id, data)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decode a MIT command frame and advance the motor one step toward it.
+fn step_mit(motor: &mut SimMotor, data: &[u8]) {
+    let limits = motor.motor_type.get_limits();
+
+    let q_raw = ((data[0] as u32) << 8) | data[1] as u32;
+    let dq_raw = ((data[2] as u32) << 4) | ((data[3] >> 4) as u32);
+    let kp_raw = (((data[3] & 0x0F) as u32) << 8) | data[4] as u32;
+
+    let target_q = LimitParam::uint_to_float(q_raw, limits.p_max, 16);
+    let target_dq = LimitParam::uint_to_float(dq_raw, limits.v_max, 12);
+    let kp = (kp_raw as f64 / 4095.0) * 500.0;
+
+    let now = Instant::now();
+    let dt = motor
+        .last_update
+        .map(|t| now.duration_since(t).as_secs_f64())
+        .unwrap_or(0.0);
+    motor.last_update = Some(now);
+
+    // Advance toward the target without overshooting.
+    let step = target_dq.abs() * dt;
+    let error = target_q - motor.position;
+    let new_position = if error.abs() <= step {
+        target_q
+    } else {
+        motor.position + step * error.signum()
+    };
+    motor.velocity = if dt > 0.0 {
+        (new_position - motor.position) / dt
+    } else {
+        0.0
+    };
+    // Torque tracks the residual position error via the commanded gain.
+    motor.torque = kp * (target_q - new_position);
+    motor.position = new_position;
+}
+
+/// Pack a motor's current state into a Damiao feedback frame.
+fn synth_feedback(motor: &SimMotor) -> (u32, Vec<u8>) {
+    let limits = motor.motor_type.get_limits();
+
+    let q = LimitParam::float_to_uint(motor.position, limits.p_max, 16);
+    let dq = LimitParam::float_to_uint(motor.velocity, limits.v_max, 12);
+    let tau = LimitParam::float_to_uint(motor.torque, limits.t_max, 12);
+
+    let error_state: u8 = if motor.enabled { 1 } else { 0 };
+    let id = (motor.recv_can_id & 0x0F) as u8;
+
+    let data = vec![
+        (error_state << 4) | id,
+        ((q >> 8) & 0xFF) as u8,
+        (q & 0xFF) as u8,
+        ((dq >> 4) & 0xFF) as u8,
+        ((((dq & 0x0F) << 4) | ((tau >> 8) & 0x0F)) & 0xFF) as u8,
+        (tau & 0xFF) as u8,
+        25,
+        25,
+    ];
+    (motor.recv_can_id, data)
+}
@@ -10,12 +10,33 @@ use std::time::Duration;
 pub struct CanFrame {
     pub can_id: u32,
     pub data: Vec<u8>,
+    /// Whether the identifier is a 29-bit extended id.
+    pub is_extended: bool,
+    /// Kernel receive timestamp in microseconds since the Unix epoch, present
+    /// only on frames read through the timestamping path.
+    pub timestamp_us: Option<u64>,
 }
 
 impl CanFrame {
-    /// Create a new CAN frame.
+    /// Create a new CAN frame, auto-selecting an extended id when `can_id`
+    /// exceeds the 11-bit standard range.
     pub fn new(can_id: u32, data: Vec<u8>) -> Self {
-        Self { can_id, data }
+        Self {
+            can_id,
+            data,
+            is_extended: can_id > 0x7FF,
+            timestamp_us: None,
+        }
+    }
+
+    /// Create a new CAN frame with an explicit extended/standard id flag.
+    pub fn new_with_ext(can_id: u32, data: Vec<u8>, is_extended: bool) -> Self {
+        Self {
+            can_id,
+            data,
+            is_extended,
+            timestamp_us: None,
+        }
     }
 }
 
@@ -25,12 +46,173 @@ pub struct CanFdFrame {
     pub can_id: u32,
     pub data: Vec<u8>,
     pub flags: u8,
+    /// Whether the identifier is a 29-bit extended id.
+    pub is_extended: bool,
+    /// Kernel receive timestamp in microseconds since the Unix epoch, present
+    /// only on frames read through the timestamping path.
+    pub timestamp_us: Option<u64>,
 }
 
 impl CanFdFrame {
-    /// Create a new CAN-FD frame.
+    /// Bit-rate-switch flag: run the data phase at the faster bitrate.
+    pub const BRS: u8 = 0x01;
+    /// Error-state-indicator flag.
+    pub const ESI: u8 = 0x02;
+
+    /// Create a new CAN-FD frame, auto-selecting an extended id when `can_id`
+    /// exceeds the 11-bit standard range.
     pub fn new(can_id: u32, data: Vec<u8>, flags: u8) -> Self {
-        Self { can_id, data, flags }
+        Self {
+            can_id,
+            data,
+            flags,
+            is_extended: can_id > 0x7FF,
+            timestamp_us: None,
+        }
+    }
+
+    /// Create a new CAN-FD frame with an explicit extended/standard id flag.
+    pub fn new_with_ext(can_id: u32, data: Vec<u8>, flags: u8, is_extended: bool) -> Self {
+        Self {
+            can_id,
+            data,
+            flags,
+            is_extended,
+            timestamp_us: None,
+        }
+    }
+}
+
+/// Controller error state derived from a CAN error frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusState {
+    /// Error counters are within the normal range.
+    ErrorActive,
+    /// A counter has crossed the warning limit (96).
+    ErrorWarning,
+    /// A counter has crossed the passive limit (128).
+    ErrorPassive,
+    /// The controller has taken itself off the bus.
+    BusOff,
+}
+
+/// A decoded CAN error frame, classified by the error class bits carried in
+/// its arbitration id (see `linux/can/error.h`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CanBusError {
+    /// TX timeout (by netdevice driver).
+    TxTimeout,
+    /// Lost arbitration.
+    LostArbitration,
+    /// Controller problem; carries the derived [`BusState`] from `data[1]`.
+    Controller(BusState),
+    /// Protocol (frame format) violation.
+    Protocol,
+    /// Transceiver status problem.
+    Transceiver,
+    /// Received no ACK on transmission.
+    NoAck,
+    /// Bus-off condition.
+    BusOff,
+    /// Bus error (bit, stuff, form, ...).
+    BusError,
+    /// Controller restarted after bus-off.
+    Restarted,
+    /// An error class not otherwise decoded.
+    Other(u32),
+}
+
+/// Decode the error class bits of a received error frame into a [`CanBusError`].
+fn decode_error_frame(can_id: u32, data: &[u8]) -> CanBusError {
+    let class = can_id & libc::CAN_ERR_MASK;
+    if class & libc::CAN_ERR_BUSOFF != 0 {
+        CanBusError::BusOff
+    } else if class & libc::CAN_ERR_TX_TIMEOUT != 0 {
+        CanBusError::TxTimeout
+    } else if class & libc::CAN_ERR_LOSTARB != 0 {
+        CanBusError::LostArbitration
+    } else if class & libc::CAN_ERR_ACK != 0 {
+        CanBusError::NoAck
+    } else if class & libc::CAN_ERR_CRTL != 0 {
+        let ctrl = data.get(1).copied().unwrap_or(0);
+        let passive = (libc::CAN_ERR_CRTL_RX_PASSIVE | libc::CAN_ERR_CRTL_TX_PASSIVE) as u8;
+        let warning = (libc::CAN_ERR_CRTL_RX_WARNING | libc::CAN_ERR_CRTL_TX_WARNING) as u8;
+        let state = if ctrl & passive != 0 {
+            BusState::ErrorPassive
+        } else if ctrl & warning != 0 {
+            BusState::ErrorWarning
+        } else {
+            BusState::ErrorActive
+        };
+        CanBusError::Controller(state)
+    } else if class & libc::CAN_ERR_PROT != 0 {
+        CanBusError::Protocol
+    } else if class & libc::CAN_ERR_TRX != 0 {
+        CanBusError::Transceiver
+    } else if class & libc::CAN_ERR_BUSERROR != 0 {
+        CanBusError::BusError
+    } else if class & libc::CAN_ERR_RESTARTED != 0 {
+        CanBusError::Restarted
+    } else {
+        CanBusError::Other(class)
+    }
+}
+
+/// Build a socketcan identifier, selecting extended form when requested or
+/// when the value exceeds the 11-bit standard range.
+fn make_can_id(can_id: u32, is_extended: bool) -> Result<socketcan::Id> {
+    if is_extended || can_id > 0x7FF {
+        Ok(socketcan::Id::Extended(
+            socketcan::ExtendedId::new(can_id).ok_or(OpenArmError::InvalidCanId(can_id))?,
+        ))
+    } else {
+        Ok(socketcan::Id::Standard(
+            socketcan::StandardId::new(can_id as u16).ok_or(OpenArmError::InvalidCanId(can_id))?,
+        ))
+    }
+}
+
+/// Decode a socketcan identifier into its raw value and extended flag.
+fn decode_can_id(id: socketcan::Id) -> (u32, bool) {
+    match id {
+        socketcan::Id::Standard(s) => (s.as_raw() as u32, false),
+        socketcan::Id::Extended(e) => (e.as_raw(), true),
+    }
+}
+
+/// A received frame that borrows the driver's frame buffer instead of copying
+/// it into a `Vec`.
+///
+/// Obtained from [`CANSocket::read_token`]. Call [`consume`](RxToken::consume)
+/// to inspect the payload as a borrowed slice; the frame is dropped when the
+/// closure returns, so no per-frame heap allocation occurs on the receive path.
+pub enum RxToken {
+    /// A standard CAN frame.
+    Can(socketcan::CanFrame),
+    /// A CAN-FD frame.
+    CanFd(socketcan::CanFdFrame),
+    /// A frame whose payload is already owned (e.g. read from a remote
+    /// socket that cannot expose an in-place buffer).
+    Owned(u32, Vec<u8>),
+}
+
+impl RxToken {
+    /// The arbitration id of the received frame.
+    pub fn can_id(&self) -> u32 {
+        match self {
+            RxToken::Can(frame) => frame.raw_id(),
+            RxToken::CanFd(frame) => frame.raw_id(),
+            RxToken::Owned(can_id, _) => *can_id,
+        }
+    }
+
+    /// Invoke `f` with the frame id and its payload borrowed in place.
+    pub fn consume<R>(self, f: impl FnOnce(u32, &[u8]) -> R) -> R {
+        match self {
+            RxToken::Can(frame) => f(frame.raw_id(), frame.data()),
+            RxToken::CanFd(frame) => f(frame.raw_id(), frame.data()),
+            RxToken::Owned(can_id, data) => f(can_id, &data),
+        }
     }
 }
 
@@ -120,8 +302,7 @@ impl CANSocket {
         let inner = self.inner.as_ref().ok_or(OpenArmError::SocketNotOpen)?;
 
         let can_frame = socketcan::CanFrame::new(
-            socketcan::StandardId::new(frame.can_id as u16)
-                .ok_or(OpenArmError::InvalidCanId(frame.can_id))?,
+            make_can_id(frame.can_id, frame.is_extended)?,
             &frame.data,
         )
         .ok_or_else(|| OpenArmError::SocketError("Failed to create CAN frame".to_string()))?;
@@ -148,15 +329,17 @@ impl CANSocket {
         match inner {
             SocketInner::Can(_) => Err(OpenArmError::CanFdNotSupported),
             SocketInner::CanFd(sock) => {
-                let fd_frame = socketcan::CanFdFrame::new(
-                    socketcan::StandardId::new(frame.can_id as u16)
-                        .ok_or(OpenArmError::InvalidCanId(frame.can_id))?,
+                let mut fd_frame = socketcan::CanFdFrame::new(
+                    make_can_id(frame.can_id, frame.is_extended)?,
                     &frame.data,
                 )
                 .ok_or_else(|| {
                     OpenArmError::SocketError("Failed to create CAN-FD frame".to_string())
                 })?;
 
+                fd_frame.set_brs(frame.flags & CanFdFrame::BRS != 0);
+                fd_frame.set_esi(frame.flags & CanFdFrame::ESI != 0);
+
                 sock.write_frame(&fd_frame).map_err(|e| {
                     OpenArmError::SocketError(format!("Failed to write CAN-FD frame: {}", e))
                 })?;
@@ -171,19 +354,29 @@ impl CANSocket {
 
         match inner {
             SocketInner::Can(sock) => match sock.read_frame() {
-                Ok(frame) => Ok(Some(CanFrame {
-                    can_id: frame.raw_id(),
-                    data: frame.data().to_vec(),
-                })),
+                Ok(frame) => {
+                    let (can_id, is_extended) = decode_can_id(frame.id());
+                    Ok(Some(CanFrame {
+                        can_id,
+                        data: frame.data().to_vec(),
+                        is_extended,
+                        timestamp_us: None,
+                    }))
+                }
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(OpenArmError::IoError(e)),
             },
             SocketInner::CanFd(sock) => match sock.read_frame() {
-                Ok(frame) => Ok(Some(CanFrame {
-                    can_id: frame.raw_id(),
-                    data: frame.data().to_vec(),
-                })),
+                Ok(frame) => {
+                    let (can_id, is_extended) = decode_can_id(frame.id());
+                    Ok(Some(CanFrame {
+                        can_id,
+                        data: frame.data().to_vec(),
+                        is_extended,
+                        timestamp_us: None,
+                    }))
+                }
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(OpenArmError::IoError(e)),
@@ -198,11 +391,23 @@ impl CANSocket {
         match inner {
             SocketInner::Can(_) => Err(OpenArmError::CanFdNotSupported),
             SocketInner::CanFd(sock) => match sock.read_frame() {
-                Ok(frame) => Ok(Some(CanFdFrame {
-                    can_id: frame.raw_id(),
-                    data: frame.data().to_vec(),
-                    flags: 0, // socketcan crate doesn't expose flags directly
-                })),
+                Ok(frame) => {
+                    let (can_id, is_extended) = decode_can_id(frame.id());
+                    let mut flags = 0u8;
+                    if frame.is_brs() {
+                        flags |= CanFdFrame::BRS;
+                    }
+                    if frame.is_esi() {
+                        flags |= CanFdFrame::ESI;
+                    }
+                    Ok(Some(CanFdFrame {
+                        can_id,
+                        data: frame.data().to_vec(),
+                        flags,
+                        is_extended,
+                        timestamp_us: None,
+                    }))
+                }
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(OpenArmError::IoError(e)),
@@ -276,6 +481,129 @@ impl CANSocket {
         Ok(())
     }
 
+    /// Install kernel-level receive filters via `CAN_RAW_FILTER`.
+    ///
+    /// Each `(can_id, can_mask)` pair admits a frame when
+    /// `received_id & can_mask == can_id & can_mask`; set the `CAN_INV_FILTER`
+    /// high bit in `can_id` to invert a rule. An empty slice installs a
+    /// zero-length filter, so the kernel drops all incoming traffic.
+    pub fn set_filters(&mut self, filters: &[(u32, u32)]) -> Result<()> {
+        let fd = self.raw_fd().ok_or(OpenArmError::SocketNotOpen)?;
+
+        let can_filters: Vec<libc::can_filter> = filters
+            .iter()
+            .map(|&(can_id, can_mask)| libc::can_filter { can_id, can_mask })
+            .collect();
+
+        let ret = unsafe {
+            libc::setsockopt(
+                fd,
+                libc::SOL_CAN_RAW,
+                libc::CAN_RAW_FILTER,
+                can_filters.as_ptr() as *const libc::c_void,
+                std::mem::size_of_val(can_filters.as_slice()) as libc::socklen_t,
+            )
+        };
+
+        if ret < 0 {
+            Err(OpenArmError::IoError(std::io::Error::last_os_error()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Enable kernel receive timestamps (`SO_TIMESTAMPNS`) on the socket.
+    ///
+    /// Once enabled, each frame read through [`read_raw_ts`](Self::read_raw_ts)
+    /// carries the nanosecond software timestamp the kernel recorded on
+    /// reception, letting control loops measure bus latency and jitter.
+    pub fn enable_timestamps(&mut self) -> Result<()> {
+        let fd = self.raw_fd().ok_or(OpenArmError::SocketNotOpen)?;
+
+        let enable: libc::c_int = 1;
+        let ret = unsafe {
+            libc::setsockopt(
+                fd,
+                libc::SOL_SOCKET,
+                libc::SO_TIMESTAMPNS,
+                &enable as *const libc::c_int as *const libc::c_void,
+                std::mem::size_of_val(&enable) as libc::socklen_t,
+            )
+        };
+
+        if ret < 0 {
+            Err(OpenArmError::IoError(std::io::Error::last_os_error()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Read a raw frame together with its kernel receive timestamp.
+    ///
+    /// Uses `recvmsg` with an ancillary-data buffer so the `SCM_TIMESTAMPNS`
+    /// control message (a `struct timespec`) can be recovered alongside the
+    /// payload. The timestamp is a [`Duration`] since the Unix epoch, or `None`
+    /// when no control message is present (e.g. timestamps were never enabled
+    /// via [`enable_timestamps`](Self::enable_timestamps)). Blocking and
+    /// timeout behaviour match [`read_raw`](Self::read_raw): a timeout returns
+    /// `Ok(None)`.
+    pub fn read_raw_ts(&self) -> Result<Option<(u32, Vec<u8>, Option<Duration>)>> {
+        let fd = self.raw_fd().ok_or(OpenArmError::SocketNotOpen)?;
+
+        // A canfd_frame is a superset of can_frame and both carry the length at
+        // offset 4 with the payload at offset 8, so one buffer serves both.
+        let mut frame_buf = [0u8; std::mem::size_of::<libc::canfd_frame>()];
+        let mut iov = libc::iovec {
+            iov_base: frame_buf.as_mut_ptr() as *mut libc::c_void,
+            iov_len: frame_buf.len(),
+        };
+
+        // Room for a single SCM_TIMESTAMPNS cmsg plus its header and alignment.
+        let mut cmsg_buf = [0u8; 64];
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = cmsg_buf.as_mut_ptr() as *mut libc::c_void;
+        msg.msg_controllen = cmsg_buf.len() as _;
+
+        let n = unsafe { libc::recvmsg(fd, &mut msg, 0) };
+        if n < 0 {
+            let err = std::io::Error::last_os_error();
+            return match err.kind() {
+                std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => Ok(None),
+                _ => Err(OpenArmError::IoError(err)),
+            };
+        }
+
+        let can_id = u32::from_ne_bytes([frame_buf[0], frame_buf[1], frame_buf[2], frame_buf[3]]);
+        let len = frame_buf[4] as usize;
+        let data = frame_buf[8..8 + len].to_vec();
+
+        // Strip the error/extended/rtr flags from the arbitration id so callers
+        // see the same value as the socketcan read paths.
+        let can_id = if can_id & libc::CAN_EFF_FLAG != 0 {
+            can_id & libc::CAN_EFF_MASK
+        } else {
+            can_id & libc::CAN_SFF_MASK
+        };
+
+        let mut timestamp = None;
+        let mut cmsg = unsafe { libc::CMSG_FIRSTHDR(&msg) };
+        while !cmsg.is_null() {
+            let hdr = unsafe { &*cmsg };
+            if hdr.cmsg_level == libc::SOL_SOCKET && hdr.cmsg_type == libc::SCM_TIMESTAMPNS {
+                let ts = unsafe {
+                    std::ptr::read_unaligned(libc::CMSG_DATA(cmsg) as *const libc::timespec)
+                };
+                timestamp = Some(Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32));
+                break;
+            }
+            cmsg = unsafe { libc::CMSG_NXTHDR(&msg, cmsg) };
+        }
+
+        Ok(Some((can_id, data, timestamp)))
+    }
+
     /// Get raw file descriptor.
     pub fn raw_fd(&self) -> Option<i32> {
         self.inner.as_ref().map(|inner| match inner {
@@ -288,12 +616,8 @@ impl CANSocket {
     pub fn write_raw(&self, can_id: u32, data: &[u8]) -> Result<()> {
         let inner = self.inner.as_ref().ok_or(OpenArmError::SocketNotOpen)?;
 
-        let frame = socketcan::CanFrame::new(
-            socketcan::StandardId::new(can_id as u16)
-                .ok_or(OpenArmError::InvalidCanId(can_id))?,
-            data,
-        )
-        .ok_or_else(|| OpenArmError::SocketError("Failed to create frame".to_string()))?;
+        let frame = socketcan::CanFrame::new(make_can_id(can_id, false)?, data)
+            .ok_or_else(|| OpenArmError::SocketError("Failed to create frame".to_string()))?;
 
         match inner {
             SocketInner::Can(sock) => sock.write_frame(&frame)?,
@@ -309,31 +633,241 @@ impl CANSocket {
         match inner {
             SocketInner::Can(_) => Err(OpenArmError::CanFdNotSupported),
             SocketInner::CanFd(sock) => {
-                let frame = socketcan::CanFdFrame::new(
-                    socketcan::StandardId::new(can_id as u16)
-                        .ok_or(OpenArmError::InvalidCanId(can_id))?,
-                    data,
-                )
-                .ok_or_else(|| OpenArmError::SocketError("Failed to create frame".to_string()))?;
+                let frame = socketcan::CanFdFrame::new(make_can_id(can_id, false)?, data)
+                    .ok_or_else(|| {
+                        OpenArmError::SocketError("Failed to create frame".to_string())
+                    })?;
                 sock.write_frame(&frame)?;
                 Ok(())
             }
         }
     }
 
+    /// Drain up to `max` frames in a single `recvmmsg` syscall.
+    ///
+    /// Amortizes the per-frame syscall cost when a whole arm of motors floods
+    /// the bus each cycle. Honors the socket's receive timeout and returns an
+    /// empty vector on `WouldBlock`/`TimedOut`.
+    pub fn read_raw_batch(&self, max: usize) -> Result<Vec<(u32, Vec<u8>)>> {
+        let fd = self.raw_fd().ok_or(OpenArmError::SocketNotOpen)?;
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+
+        let frame_sz = std::mem::size_of::<libc::canfd_frame>();
+        let mut bufs: Vec<Vec<u8>> = vec![vec![0u8; frame_sz]; max];
+        let mut iovecs: Vec<libc::iovec> = bufs
+            .iter_mut()
+            .map(|b| libc::iovec {
+                iov_base: b.as_mut_ptr() as *mut libc::c_void,
+                iov_len: b.len(),
+            })
+            .collect();
+
+        let mut msgs: Vec<libc::mmsghdr> = (0..max)
+            .map(|i| {
+                let mut m: libc::mmsghdr = unsafe { std::mem::zeroed() };
+                m.msg_hdr.msg_iov = &mut iovecs[i];
+                m.msg_hdr.msg_iovlen = 1;
+                m
+            })
+            .collect();
+
+        let n = unsafe {
+            libc::recvmmsg(
+                fd,
+                msgs.as_mut_ptr(),
+                max as libc::c_uint,
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+
+        if n < 0 {
+            let err = std::io::Error::last_os_error();
+            return match err.kind() {
+                std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => Ok(Vec::new()),
+                _ => Err(OpenArmError::IoError(err)),
+            };
+        }
+
+        let mut out = Vec::with_capacity(n as usize);
+        for buf in bufs.iter().take(n as usize) {
+            let raw_id = u32::from_ne_bytes([buf[0], buf[1], buf[2], buf[3]]);
+            let can_id = if raw_id & libc::CAN_EFF_FLAG != 0 {
+                raw_id & libc::CAN_EFF_MASK
+            } else {
+                raw_id & libc::CAN_SFF_MASK
+            };
+            let len = buf[4] as usize;
+            out.push((can_id, buf[8..8 + len].to_vec()));
+        }
+        Ok(out)
+    }
+
+    /// Send a batch of frames in a single `sendmmsg` syscall.
+    ///
+    /// The inverse of [`read_raw_batch`](Self::read_raw_batch); writes every
+    /// standard CAN frame in `frames` with one syscall.
+    pub fn write_raw_batch(&self, frames: &[(u32, &[u8])]) -> Result<()> {
+        let fd = self.raw_fd().ok_or(OpenArmError::SocketNotOpen)?;
+        if frames.is_empty() {
+            return Ok(());
+        }
+
+        let mut can_frames: Vec<libc::can_frame> = Vec::with_capacity(frames.len());
+        for &(can_id, data) in frames {
+            let mut cf: libc::can_frame = unsafe { std::mem::zeroed() };
+            cf.can_id = if can_id > 0x7FF {
+                can_id | libc::CAN_EFF_FLAG
+            } else {
+                can_id
+            };
+            cf.can_dlc = data.len() as u8;
+            cf.data[..data.len()].copy_from_slice(data);
+            can_frames.push(cf);
+        }
+
+        let mut iovecs: Vec<libc::iovec> = can_frames
+            .iter_mut()
+            .map(|f| libc::iovec {
+                iov_base: f as *mut libc::can_frame as *mut libc::c_void,
+                iov_len: std::mem::size_of::<libc::can_frame>(),
+            })
+            .collect();
+
+        let mut msgs: Vec<libc::mmsghdr> = (0..frames.len())
+            .map(|i| {
+                let mut m: libc::mmsghdr = unsafe { std::mem::zeroed() };
+                m.msg_hdr.msg_iov = &mut iovecs[i];
+                m.msg_hdr.msg_iovlen = 1;
+                m
+            })
+            .collect();
+
+        let n = unsafe {
+            libc::sendmmsg(fd, msgs.as_mut_ptr(), frames.len() as libc::c_uint, 0)
+        };
+
+        if n < 0 {
+            let err = std::io::Error::last_os_error();
+            // Kernels without sendmmsg fall back to per-frame sends.
+            if err.raw_os_error() == Some(libc::ENOSYS) {
+                for &(can_id, data) in frames {
+                    self.write_raw(can_id, data)?;
+                }
+                return Ok(());
+            }
+            Err(OpenArmError::IoError(err))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Enable reception of CAN error frames via `CAN_RAW_ERR_FILTER`.
+    ///
+    /// `err_mask` selects which error classes are delivered (a bitwise-OR of
+    /// the `CAN_ERR_*` class flags, or [`libc::CAN_ERR_MASK`] for all). Error
+    /// frames then surface through [`read_raw_checked`](Self::read_raw_checked)
+    /// as [`OpenArmError::BusError`] rather than as ordinary data frames.
+    pub fn enable_error_frames(&mut self, err_mask: u32) -> Result<()> {
+        let fd = self.raw_fd().ok_or(OpenArmError::SocketNotOpen)?;
+
+        let ret = unsafe {
+            libc::setsockopt(
+                fd,
+                libc::SOL_CAN_RAW,
+                libc::CAN_RAW_ERR_FILTER,
+                &err_mask as *const u32 as *const libc::c_void,
+                std::mem::size_of_val(&err_mask) as libc::socklen_t,
+            )
+        };
+
+        if ret < 0 {
+            Err(OpenArmError::IoError(std::io::Error::last_os_error()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Read a raw frame, surfacing CAN error frames as errors.
+    ///
+    /// Behaves like [`read_raw`](Self::read_raw) for ordinary frames, but when
+    /// the received id carries `CAN_ERR_FLAG` the frame is decoded into a
+    /// [`CanBusError`] and returned as [`OpenArmError::BusError`] so callers can
+    /// react to bus-off or controller faults (for instance by reinitializing
+    /// the socket). Requires error reception to be enabled first via
+    /// [`enable_error_frames`](Self::enable_error_frames).
+    pub fn read_raw_checked(&self) -> Result<Option<(u32, Vec<u8>)>> {
+        let fd = self.raw_fd().ok_or(OpenArmError::SocketNotOpen)?;
+
+        let mut buf = [0u8; std::mem::size_of::<libc::canfd_frame>()];
+        let n = unsafe {
+            libc::recv(
+                fd,
+                buf.as_mut_ptr() as *mut libc::c_void,
+                buf.len(),
+                0,
+            )
+        };
+
+        if n < 0 {
+            let err = std::io::Error::last_os_error();
+            return match err.kind() {
+                std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => Ok(None),
+                _ => Err(OpenArmError::IoError(err)),
+            };
+        }
+
+        let raw_id = u32::from_ne_bytes([buf[0], buf[1], buf[2], buf[3]]);
+        let len = buf[4] as usize;
+
+        if raw_id & libc::CAN_ERR_FLAG != 0 {
+            return Err(OpenArmError::BusError(decode_error_frame(raw_id, &buf[8..8 + len])));
+        }
+
+        let can_id = if raw_id & libc::CAN_EFF_FLAG != 0 {
+            raw_id & libc::CAN_EFF_MASK
+        } else {
+            raw_id & libc::CAN_SFF_MASK
+        };
+        Ok(Some((can_id, buf[8..8 + len].to_vec())))
+    }
+
     /// Read raw CAN frame.
     pub fn read_raw(&self) -> Result<Option<(u32, Vec<u8>)>> {
         let inner = self.inner.as_ref().ok_or(OpenArmError::SocketNotOpen)?;
 
         match inner {
             SocketInner::Can(sock) => match sock.read_frame() {
-                Ok(frame) => Ok(Some((frame.raw_id(), frame.data().to_vec()))),
+                Ok(frame) => Ok(Some((decode_can_id(frame.id()).0, frame.data().to_vec()))),
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
+                Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
+                Err(e) => Err(OpenArmError::IoError(e)),
+            },
+            SocketInner::CanFd(sock) => match sock.read_frame() {
+                Ok(frame) => Ok(Some((decode_can_id(frame.id()).0, frame.data().to_vec()))),
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
+                Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
+                Err(e) => Err(OpenArmError::IoError(e)),
+            },
+        }
+    }
+
+    /// Read a frame as a borrowed token, avoiding the per-frame `Vec`
+    /// allocation of [`read_raw`](Self::read_raw). Returns `None` on timeout.
+    pub fn read_token(&self) -> Result<Option<RxToken>> {
+        let inner = self.inner.as_ref().ok_or(OpenArmError::SocketNotOpen)?;
+
+        match inner {
+            SocketInner::Can(sock) => match sock.read_frame() {
+                Ok(frame) => Ok(Some(RxToken::Can(frame))),
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(OpenArmError::IoError(e)),
             },
             SocketInner::CanFd(sock) => match sock.read_frame() {
-                Ok(frame) => Ok(Some((frame.raw_id(), frame.data().to_vec()))),
+                Ok(frame) => Ok(Some(RxToken::CanFd(frame))),
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(OpenArmError::IoError(e)),
@@ -375,15 +909,35 @@ pub enum AnyCANSocket {
     Local(CANSocket),
     /// Remote CAN socket via xoq P2P
     Remote(xoq::socketcan::RemoteCanSocket),
+    /// Software-simulated bus for hardware-free operation
+    Simulated(crate::canbus::SimulatedCanSocket),
+    /// Raw in-memory loopback bus, for testing the dispatch path itself
+    Virtual(crate::canbus::VirtualCanBackend),
 }
 
 #[cfg(feature = "remote")]
 impl AnyCANSocket {
+    /// Create a simulated bus backend with no motors registered.
+    pub fn simulated() -> Self {
+        AnyCANSocket::Simulated(crate::canbus::SimulatedCanSocket::new())
+    }
+
+    /// Create a connected pair of in-memory loopback backends: a frame
+    /// written to one is read back from the other, and vice versa. Useful
+    /// for driving two [`AnyCANDeviceCollection`](crate::canbus::AnyCANDeviceCollection)s
+    /// against each other without a kernel socket or xoq server.
+    pub fn virtual_pair() -> (Self, Self) {
+        let (a, b) = crate::canbus::VirtualCanBackend::loopback_pair();
+        (AnyCANSocket::Virtual(a), AnyCANSocket::Virtual(b))
+    }
+
     /// Check if socket is open/connected.
     pub fn is_open(&self) -> bool {
         match self {
             AnyCANSocket::Local(s) => s.is_open(),
             AnyCANSocket::Remote(s) => xoq::can::CanBusSocket::is_open(s),
+            AnyCANSocket::Simulated(s) => s.is_open(),
+            AnyCANSocket::Virtual(s) => s.is_open(),
         }
     }
 
@@ -393,6 +947,8 @@ impl AnyCANSocket {
             AnyCANSocket::Local(s) => s.write_raw(can_id, data),
             AnyCANSocket::Remote(s) => xoq::can::CanBusSocket::write_raw(s, can_id, data)
                 .map_err(|e| OpenArmError::SocketError(e.to_string())),
+            AnyCANSocket::Simulated(s) => s.write_raw(can_id, data),
+            AnyCANSocket::Virtual(s) => s.write_raw(can_id, data),
         }
     }
 
@@ -402,6 +958,8 @@ impl AnyCANSocket {
             AnyCANSocket::Local(s) => s.read_raw(),
             AnyCANSocket::Remote(s) => xoq::can::CanBusSocket::read_raw(s)
                 .map_err(|e| OpenArmError::SocketError(e.to_string())),
+            AnyCANSocket::Simulated(s) => s.read_raw(),
+            AnyCANSocket::Virtual(s) => s.read_raw(),
         }
     }
 
@@ -411,6 +969,8 @@ impl AnyCANSocket {
             AnyCANSocket::Local(s) => s.is_data_available(timeout_us),
             AnyCANSocket::Remote(s) => xoq::can::CanBusSocket::is_data_available(s, timeout_us)
                 .map_err(|e| OpenArmError::SocketError(e.to_string())),
+            AnyCANSocket::Simulated(s) => s.is_data_available(timeout_us),
+            AnyCANSocket::Virtual(s) => s.is_data_available(timeout_us),
         }
     }
 
@@ -420,6 +980,76 @@ impl AnyCANSocket {
             AnyCANSocket::Local(s) => s.set_recv_timeout(timeout_us),
             AnyCANSocket::Remote(s) => xoq::can::CanBusSocket::set_recv_timeout(s, timeout_us)
                 .map_err(|e| OpenArmError::SocketError(e.to_string())),
+            AnyCANSocket::Simulated(_) => Ok(()),
+            AnyCANSocket::Virtual(_) => Ok(()),
+        }
+    }
+
+    /// Read a frame as a token. Local sockets yield a borrowed token; remote
+    /// sockets fall back to an owned-payload token. Returns `None` on timeout.
+    pub fn read_token(&self) -> Result<Option<RxToken>> {
+        match self {
+            AnyCANSocket::Local(s) => s.read_token(),
+            AnyCANSocket::Remote(s) => Ok(xoq::can::CanBusSocket::read_raw(s)
+                .map_err(|e| OpenArmError::SocketError(e.to_string()))?
+                .map(|(can_id, data)| RxToken::Owned(can_id, data))),
+            AnyCANSocket::Simulated(s) => {
+                Ok(s.read_raw()?.map(|(can_id, data)| RxToken::Owned(can_id, data)))
+            }
+            AnyCANSocket::Virtual(s) => {
+                Ok(s.read_raw()?.map(|(can_id, data)| RxToken::Owned(can_id, data)))
+            }
+        }
+    }
+
+    /// Drain up to `max` frames at once. Local sockets use `recvmmsg`; remote
+    /// sockets loop over [`read_raw`](Self::read_raw) until drained or `max`.
+    pub fn read_raw_batch(&self, max: usize) -> Result<Vec<(u32, Vec<u8>)>> {
+        match self {
+            AnyCANSocket::Local(s) => s.read_raw_batch(max),
+            AnyCANSocket::Remote(s) => {
+                let mut out = Vec::new();
+                while out.len() < max {
+                    match xoq::can::CanBusSocket::read_raw(s)
+                        .map_err(|e| OpenArmError::SocketError(e.to_string()))?
+                    {
+                        Some(frame) => out.push(frame),
+                        None => break,
+                    }
+                }
+                Ok(out)
+            }
+            AnyCANSocket::Simulated(s) => s.read_raw_batch(max),
+            AnyCANSocket::Virtual(s) => s.read_raw_batch(max),
+        }
+    }
+
+    /// Send a batch of frames at once. Local sockets use `sendmmsg`; remote
+    /// sockets loop over [`write_raw`](Self::write_raw).
+    pub fn write_raw_batch(&self, frames: &[(u32, &[u8])]) -> Result<()> {
+        match self {
+            AnyCANSocket::Local(s) => s.write_raw_batch(frames),
+            AnyCANSocket::Remote(s) => {
+                for &(can_id, data) in frames {
+                    xoq::can::CanBusSocket::write_raw(s, can_id, data)
+                        .map_err(|e| OpenArmError::SocketError(e.to_string()))?;
+                }
+                Ok(())
+            }
+            AnyCANSocket::Simulated(s) => s.write_raw_batch(frames),
+            AnyCANSocket::Virtual(s) => s.write_raw_batch(frames),
+        }
+    }
+
+    /// Get the raw file descriptor when backed by a local socket.
+    ///
+    /// Returns `None` for remote sockets, which have no pollable fd.
+    pub fn raw_fd(&self) -> Option<i32> {
+        match self {
+            AnyCANSocket::Local(s) => s.raw_fd(),
+            AnyCANSocket::Remote(_) => None,
+            AnyCANSocket::Simulated(_) => None,
+            AnyCANSocket::Virtual(_) => None,
         }
     }
 
@@ -428,10 +1058,29 @@ impl AnyCANSocket {
         matches!(self, AnyCANSocket::Local(_))
     }
 
+    /// Check if this is a simulated socket.
+    pub fn is_simulated(&self) -> bool {
+        matches!(self, AnyCANSocket::Simulated(_))
+    }
+
+    /// Register a motor with the simulated backend. A no-op for non-simulated
+    /// sockets, so callers can invoke it unconditionally after initializing
+    /// motors.
+    pub fn register_simulated_motor(&self, motor: &crate::damiao_motor::Motor) {
+        if let AnyCANSocket::Simulated(s) = self {
+            s.register_motor(motor);
+        }
+    }
+
     /// Check if this is a remote socket.
     pub fn is_remote(&self) -> bool {
         matches!(self, AnyCANSocket::Remote(_))
     }
+
+    /// Check if this is an in-memory loopback socket.
+    pub fn is_virtual(&self) -> bool {
+        matches!(self, AnyCANSocket::Virtual(_))
+    }
 }
 
 #[cfg(feature = "remote")]
@@ -1,17 +1,147 @@
 //! Arm component for controlling multiple arm motors.
 
 use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
 
-use crate::canbus::{CANDeviceCollection, MotorDeviceCan};
+use crate::canbus::{
+    CANDeviceCollection, CaptureLog, CapturedFrame, FrameDirection, MotorDeviceCan, Replayer,
+};
 use crate::damiao_motor::{
-    CallbackMode, ControlMode, DMDeviceCollection, MITParam, Motor, MotorType, MotorVariable,
-    PosForceParam, PosVelParam,
+    CallbackMode, ControlMode, DMDeviceCollection, MITParam, Motor, MotorFaultStatus, MotorType,
+    MotorVariable, ParamResult, PosForceParam, PosVelParam, VelParam,
 };
 use crate::error::{OpenArmError, Result};
 
+/// A single recorded bus event: a `Tx` frame and its monotonic offset from the
+/// start of the recording.
+#[derive(Debug, Clone)]
+pub struct TrajectoryEvent {
+    /// Microseconds elapsed between `record_start` and this frame.
+    pub offset_us: u64,
+    /// Arbitration id the frame was sent to.
+    pub can_id: u32,
+    /// Raw payload bytes.
+    pub data: Vec<u8>,
+}
+
+/// A recorded motion: the timed `Tx` frames captured from the arm's control
+/// stream, replayable deterministically via [`ArmComponent::play`].
+///
+/// Inspired by ARTIQ's record-once/replay-many DMA: hand-guide the arm with the
+/// recorder running, then play the captured frame sequence back at its original
+/// timing. Trajectories serialize to a compact line-oriented text format
+/// (`offset_us id#hexdata`) so they can be saved and shared between sessions.
+#[derive(Debug, Clone, Default)]
+pub struct Trajectory {
+    events: Vec<TrajectoryEvent>,
+}
+
+impl Trajectory {
+    /// Build a trajectory from a capture, keeping only the transmitted frames.
+    pub fn from_captured(frames: &[CapturedFrame]) -> Self {
+        let events = frames
+            .iter()
+            .filter(|f| f.direction == FrameDirection::Tx)
+            .map(|f| TrajectoryEvent {
+                offset_us: f.timestamp.as_micros() as u64,
+                can_id: f.can_id,
+                data: f.data.clone(),
+            })
+            .collect();
+        Self { events }
+    }
+
+    /// The recorded events in capture order.
+    pub fn events(&self) -> &[TrajectoryEvent] {
+        &self.events
+    }
+
+    /// Number of recorded events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether the trajectory holds no events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Serialize to the compact `offset_us id#hexdata` text format.
+    pub fn serialize(&self) -> String {
+        let mut out = String::new();
+        for event in &self.events {
+            let hex: String = event.data.iter().map(|b| format!("{:02X}", b)).collect();
+            out.push_str(&format!("{} {:X}#{}\n", event.offset_us, event.can_id, hex));
+        }
+        out
+    }
+
+    /// Parse a trajectory back from the compact text format.
+    pub fn deserialize(text: &str) -> Result<Self> {
+        let mut events = Vec::new();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (offset, frame) = line
+                .split_once(' ')
+                .ok_or_else(|| OpenArmError::ConfigError(format!("malformed event: {line}")))?;
+            let (id, hex) = frame
+                .split_once('#')
+                .ok_or_else(|| OpenArmError::ConfigError(format!("malformed event: {line}")))?;
+            let offset_us = offset
+                .parse::<u64>()
+                .map_err(|_| OpenArmError::ConfigError(format!("bad offset: {offset}")))?;
+            let can_id = u32::from_str_radix(id, 16)
+                .map_err(|_| OpenArmError::ConfigError(format!("bad id: {id}")))?;
+            let data = (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+                .collect::<std::result::Result<Vec<u8>, _>>()
+                .map_err(|_| OpenArmError::ConfigError(format!("bad payload: {hex}")))?;
+            events.push(TrajectoryEvent {
+                offset_us,
+                can_id,
+                data,
+            });
+        }
+        Ok(Self { events })
+    }
+
+    /// Save the trajectory to `path` in the compact text format.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        std::fs::write(path, self.serialize()).map_err(OpenArmError::IoError)
+    }
+
+    /// Load a trajectory previously written with [`save`](Self::save).
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(OpenArmError::IoError)?;
+        Self::deserialize(&text)
+    }
+
+    /// Convert to a [`Replayer`] that re-sends every event at its offset.
+    fn to_replayer(&self) -> Replayer {
+        let frames = self
+            .events
+            .iter()
+            .map(|e| CapturedFrame {
+                timestamp: Duration::from_micros(e.offset_us),
+                direction: FrameDirection::Tx,
+                can_id: e.can_id,
+                data: e.data.clone(),
+            })
+            .collect();
+        Replayer::from_frames(frames)
+    }
+}
+
 /// Arm component wrapper for multiple arm motors.
 pub struct ArmComponent {
     inner: DMDeviceCollection,
+    /// Active recorder installed by [`record_start`](ArmComponent::record_start).
+    recorder: Mutex<Option<Arc<CaptureLog>>>,
 }
 
 impl ArmComponent {
@@ -19,9 +149,57 @@ impl ArmComponent {
     pub fn from_collection(collection: Arc<CANDeviceCollection>) -> Self {
         Self {
             inner: DMDeviceCollection::from_collection(collection),
+            recorder: Mutex::new(None),
         }
     }
 
+    /// Begin capturing every control frame into a [`Trajectory`].
+    ///
+    /// Installs a [`CaptureLog`] tracer on the shared collection so all
+    /// subsequent `*_control_all`, `set_zero_all`, etc. calls are recorded with
+    /// their monotonic offset while still being sent to the bus. Call
+    /// [`record_stop`](Self::record_stop) to retrieve the captured motion.
+    pub fn record_start(&self) {
+        let log = Arc::new(CaptureLog::new());
+        self.collection().set_tracer(Arc::clone(&log));
+        *self.recorder.lock().unwrap() = Some(log);
+    }
+
+    /// Stop recording and return the captured [`Trajectory`]. Returns an empty
+    /// trajectory if no recording was active.
+    pub fn record_stop(&self) -> Trajectory {
+        let log = self.recorder.lock().unwrap().take();
+        self.collection().clear_tracer();
+        match log {
+            Some(log) => Trajectory::from_captured(&log.frames()),
+            None => Trajectory::default(),
+        }
+    }
+
+    /// Replay a recorded trajectory `loop_count` times on a background thread,
+    /// re-sending each event through the collection at its original offset.
+    ///
+    /// Returns a join handle so the caller can await completion; a `loop_count`
+    /// of `0` replays indefinitely; the thread then ends only when a replay
+    /// fails, returning that error.
+    pub fn play(&self, trajectory: Trajectory, loop_count: u32) -> JoinHandle<Result<()>> {
+        let collection = Arc::clone(self.collection());
+        std::thread::spawn(move || {
+            let replayer = trajectory.to_replayer();
+            let mut remaining = loop_count;
+            loop {
+                collection.replay(&replayer)?;
+                if loop_count != 0 {
+                    remaining -= 1;
+                    if remaining == 0 {
+                        break;
+                    }
+                }
+            }
+            Ok(())
+        })
+    }
+
     /// Add a motor device.
     pub fn add_motor_device(&mut self, motor: Motor, device: Arc<Mutex<MotorDeviceCan>>) {
         self.inner.add_motor_device(motor, device);
@@ -119,6 +297,23 @@ impl ArmComponent {
         self.inner.query_param_one(index, rid)
     }
 
+    /// Write a register by value on one motor, returning the acknowledged
+    /// result.
+    pub fn set_param_one(
+        &self,
+        index: usize,
+        rid: MotorVariable,
+        value: f64,
+        timeout: Duration,
+    ) -> Result<ParamResult> {
+        self.inner.set_param_one(index, rid, value, timeout)
+    }
+
+    /// Write a register by value on all motors.
+    pub fn set_param_all(&self, rid: MotorVariable, value: f64) -> Result<()> {
+        self.inner.set_param_all(rid, value)
+    }
+
     /// MIT control for one motor.
     pub fn mit_control_one(&self, index: usize, param: &MITParam) -> Result<()> {
         self.inner.mit_control_one(index, param)
@@ -139,6 +334,16 @@ impl ArmComponent {
         self.inner.posvel_control_all(params)
     }
 
+    /// Velocity control for one motor.
+    pub fn vel_control_one(&self, index: usize, param: &VelParam) -> Result<()> {
+        self.inner.vel_control_one(index, param)
+    }
+
+    /// Velocity control for all motors.
+    pub fn vel_control_all(&self, params: &[VelParam]) -> Result<()> {
+        self.inner.vel_control_all(params)
+    }
+
     /// Position-force control for one motor.
     pub fn posforce_control_one(&self, index: usize, param: &PosForceParam) -> Result<()> {
         self.inner.posforce_control_one(index, param)
@@ -149,6 +354,21 @@ impl ArmComponent {
         self.inner.posforce_control_all(params)
     }
 
+    /// Clear the amplifier fault latch for one motor.
+    pub fn clear_faults_one(&self, index: usize) -> Result<()> {
+        self.inner.clear_faults_one(index)
+    }
+
+    /// Clear the amplifier fault latch for all motors.
+    pub fn clear_faults_all(&self) -> Result<()> {
+        self.inner.clear_faults_all()
+    }
+
+    /// Decode the structured fault status for one motor.
+    pub fn fault_status(&self, index: usize) -> Result<MotorFaultStatus> {
+        self.inner.fault_status(index)
+    }
+
     /// Set control mode for one motor.
     pub fn set_control_mode_one(&self, index: usize, mode: ControlMode) -> Result<()> {
         self.inner.set_control_mode_one(index, mode)
@@ -0,0 +1,316 @@
+//! Declarative configuration for building an [`OpenArm`](super::OpenArm) from a file.
+//!
+//! Instead of passing parallel `motor_types`/`send_can_ids`/`recv_can_ids`
+//! arrays that must be kept in lockstep, callers can describe the whole arm in
+//! a YAML or TOML file and load it with
+//! [`OpenArm::from_config`](super::OpenArm::from_config).
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::damiao_motor::{ControlMode, MotorType};
+use crate::error::{OpenArmError, Result};
+
+/// Top-level arm definition, usually loaded from a version-controlled file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpenArmConfig {
+    /// CAN interface name to open (e.g. `can0`). Optional in the file: the
+    /// interface is normally supplied at load time via
+    /// [`OpenArm::from_config`](super::OpenArm::from_config) so the same layout
+    /// file can be reused across physical buses.
+    #[serde(default)]
+    pub can_interface: Option<String>,
+
+    /// Whether to open the bus in CAN-FD mode.
+    #[serde(default)]
+    pub enable_fd: bool,
+
+    /// Ordered list of arm joint motors.
+    pub motors: Vec<MotorConfig>,
+
+    /// Optional gripper motor.
+    #[serde(default)]
+    pub gripper: Option<GripperConfig>,
+}
+
+/// A single arm joint motor.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MotorConfig {
+    /// Human-readable joint name used for name-based lookup.
+    pub name: String,
+
+    /// Motor model, matching a [`MotorType`] variant name (e.g. `DM4310`).
+    pub motor_type: String,
+
+    /// Outgoing (command) CAN identifier.
+    pub send_can_id: u32,
+
+    /// Incoming (feedback) CAN identifier.
+    pub recv_can_id: u32,
+
+    /// Control mode name (e.g. `MIT`); defaults to `MIT` when omitted.
+    #[serde(default)]
+    pub control_mode: Option<String>,
+
+    /// Raw-frame zero offset (rad) applied to this joint; defaults to `0.0`.
+    #[serde(default)]
+    pub offset: f64,
+
+    /// Lower position limit (rad) enforced by the safety supervisor, if any.
+    #[serde(default)]
+    pub position_min: Option<f64>,
+
+    /// Upper position limit (rad) enforced by the safety supervisor, if any.
+    #[serde(default)]
+    pub position_max: Option<f64>,
+
+    /// Maximum absolute velocity (rad/s) enforced by the safety supervisor.
+    #[serde(default)]
+    pub max_velocity: Option<f64>,
+
+    /// Maximum absolute torque/current command enforced by the safety
+    /// supervisor.
+    #[serde(default)]
+    pub max_torque: Option<f64>,
+}
+
+/// The gripper motor plus its default motion limits.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GripperConfig {
+    /// Human-readable gripper name.
+    pub name: String,
+
+    /// Motor model, matching a [`MotorType`] variant name.
+    pub motor_type: String,
+
+    /// Outgoing (command) CAN identifier.
+    pub send_can_id: u32,
+
+    /// Incoming (feedback) CAN identifier.
+    pub recv_can_id: u32,
+
+    /// Control mode name; defaults to `MIT` when omitted.
+    #[serde(default)]
+    pub control_mode: Option<String>,
+
+    /// Default speed limit applied to gripper motion, if any.
+    #[serde(default)]
+    pub speed_limit: Option<f64>,
+
+    /// Default torque limit applied to gripper motion, if any.
+    #[serde(default)]
+    pub torque_limit: Option<f64>,
+}
+
+/// Whole-robot definition describing joints and a gripper mapping.
+///
+/// Distinct from [`OpenArmConfig`] in that the gripper block carries the full
+/// open/closed/grasp position mapping used by [`GripperComponent`], letting a
+/// single file fully populate an [`OpenArm`](super::OpenArm).
+#[derive(Debug, Clone, Deserialize)]
+pub struct RobotConfig {
+    /// CAN interface name to open (e.g. `can0`).
+    pub can_interface: String,
+
+    /// Whether to open the bus in CAN-FD mode.
+    #[serde(default)]
+    pub enable_fd: bool,
+
+    /// Ordered list of arm joints.
+    pub joints: Vec<JointConfig>,
+
+    /// Optional gripper definition with its position mapping.
+    #[serde(default)]
+    pub gripper: Option<RobotGripperConfig>,
+}
+
+/// A single arm joint in a [`RobotConfig`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct JointConfig {
+    /// Motor model, matching a [`MotorType`] variant name.
+    pub motor_type: String,
+    /// Outgoing (command) CAN identifier.
+    pub send_can_id: u32,
+    /// Incoming (feedback) CAN identifier.
+    pub recv_can_id: u32,
+    /// Control mode name; defaults to `MIT` when omitted.
+    #[serde(default)]
+    pub control_mode: Option<String>,
+}
+
+/// Gripper definition with open/closed/grasp position mapping.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RobotGripperConfig {
+    /// Motor model, matching a [`MotorType`] variant name.
+    pub motor_type: String,
+    /// Outgoing (command) CAN identifier.
+    pub send_can_id: u32,
+    /// Incoming (feedback) CAN identifier.
+    pub recv_can_id: u32,
+    /// Control mode name; defaults to `MIT` when omitted.
+    #[serde(default)]
+    pub control_mode: Option<String>,
+    /// Gripper-frame fully-open position.
+    pub gripper_open_position: f64,
+    /// Gripper-frame fully-closed position.
+    pub gripper_closed_position: f64,
+    /// Gripper-frame grasp position.
+    pub gripper_grasp_position: f64,
+    /// Motor-frame position corresponding to fully open.
+    pub motor_open_position: f64,
+    /// Motor-frame position corresponding to fully closed.
+    pub motor_closed_position: f64,
+    /// Default motion speed limit (rad/s).
+    pub default_speed_rad_s: f64,
+    /// Default torque limit (per-unit).
+    pub default_torque_pu: f64,
+}
+
+/// Declarative definition of a [`MotorGroup`](super::MotorGroup): an ordered,
+/// name-keyed set of joints that carries each joint's calibration.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MotorGroupConfig {
+    /// Ordered list of joints; the order defines the group's joint order.
+    pub joints: Vec<MotorGroupJointConfig>,
+}
+
+/// A single joint in a [`MotorGroupConfig`], including its calibration.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MotorGroupJointConfig {
+    /// Human-readable joint name used for name-based lookup.
+    pub name: String,
+    /// Motor model, matching a [`MotorType`] variant name.
+    pub motor_type: String,
+    /// Outgoing (command) CAN identifier.
+    pub send_can_id: u32,
+    /// Incoming (feedback) CAN identifier.
+    pub recv_can_id: u32,
+    /// Control mode name; defaults to `MIT` when omitted.
+    #[serde(default)]
+    pub control_mode: Option<String>,
+    /// Raw-frame zero offset (rad); defaults to `0.0`.
+    #[serde(default)]
+    pub offset: f64,
+    /// Gearbox reduction ratio; defaults to `1.0`.
+    #[serde(default = "default_reduction_ratio")]
+    pub reduction_ratio: f64,
+}
+
+/// Default reduction ratio (`1.0`) for a joint that omits it.
+fn default_reduction_ratio() -> f64 {
+    1.0
+}
+
+impl MotorGroupConfig {
+    /// Load a motor-group configuration from a YAML or TOML file by extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match ext.as_str() {
+            "yaml" | "yml" => serde_yaml::from_str(&text)
+                .map_err(|e| OpenArmError::ConfigError(format!("invalid YAML config: {}", e))),
+            "toml" => toml::from_str(&text)
+                .map_err(|e| OpenArmError::ConfigError(format!("invalid TOML config: {}", e))),
+            other => Err(OpenArmError::ConfigError(format!(
+                "unsupported config extension '{}' (expected yaml, yml, or toml)",
+                other
+            ))),
+        }
+    }
+}
+
+impl RobotConfig {
+    /// Load a robot configuration from a YAML or TOML file by extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match ext.as_str() {
+            "yaml" | "yml" => serde_yaml::from_str(&text)
+                .map_err(|e| OpenArmError::ConfigError(format!("invalid YAML config: {}", e))),
+            "toml" => toml::from_str(&text)
+                .map_err(|e| OpenArmError::ConfigError(format!("invalid TOML config: {}", e))),
+            other => Err(OpenArmError::ConfigError(format!(
+                "unsupported config extension '{}' (expected yaml, yml, or toml)",
+                other
+            ))),
+        }
+    }
+}
+
+impl OpenArmConfig {
+    /// Load a configuration from a YAML (`.yaml`/`.yml`) or TOML (`.toml`) file,
+    /// selected by the path extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match ext.as_str() {
+            "yaml" | "yml" => serde_yaml::from_str(&text)
+                .map_err(|e| OpenArmError::ConfigError(format!("invalid YAML config: {}", e))),
+            "toml" => toml::from_str(&text)
+                .map_err(|e| OpenArmError::ConfigError(format!("invalid TOML config: {}", e))),
+            other => Err(OpenArmError::ConfigError(format!(
+                "unsupported config extension '{}' (expected yaml, yml, or toml)",
+                other
+            ))),
+        }
+    }
+}
+
+/// Resolve a [`MotorType`] from its variant name.
+pub(crate) fn parse_motor_type(name: &str) -> Result<MotorType> {
+    let ty = match name {
+        "DM3507" => MotorType::DM3507,
+        "DM4310" => MotorType::DM4310,
+        "DM4310_48V" => MotorType::DM4310_48V,
+        "DM4340" => MotorType::DM4340,
+        "DM4340_48V" => MotorType::DM4340_48V,
+        "DM6006" => MotorType::DM6006,
+        "DM8006" => MotorType::DM8006,
+        "DM8009" => MotorType::DM8009,
+        "DM10010L" => MotorType::DM10010L,
+        "DM10010" => MotorType::DM10010,
+        "DMH3510" => MotorType::DMH3510,
+        "DMH6215" => MotorType::DMH6215,
+        "DMG6220" => MotorType::DMG6220,
+        other => {
+            return Err(OpenArmError::ConfigError(format!(
+                "unknown motor_type '{}'",
+                other
+            )))
+        }
+    };
+    Ok(ty)
+}
+
+/// Resolve a [`ControlMode`] from its variant name, defaulting to `MIT`.
+pub(crate) fn parse_control_mode(name: Option<&str>) -> Result<ControlMode> {
+    let mode = match name.unwrap_or("MIT") {
+        "MIT" => ControlMode::MIT,
+        "POS_VEL" => ControlMode::POS_VEL,
+        "VEL" => ControlMode::VEL,
+        "POS_FORCE" => ControlMode::POS_FORCE,
+        other => {
+            return Err(OpenArmError::ConfigError(format!(
+                "unknown control_mode '{}'",
+                other
+            )))
+        }
+    };
+    Ok(mode)
+}
@@ -1,13 +1,97 @@
 //! Gripper component for controlling a gripper motor.
 
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use crate::canbus::{CANDeviceCollection, MotorDeviceCan};
 use crate::damiao_motor::{
-    CallbackMode, ControlMode, DMDeviceCollection, MITParam, Motor, MotorType,
-    MotorVariable, PosForceParam, PosVelParam,
+    CallbackMode, ControlMode, DMDeviceCollection, MITParam, Motor, MotorFaultStatus, MotorType,
+    MotorVariable, ParamResult, PosForceParam, PosVelParam,
 };
-use crate::error::Result;
+use crate::error::{OpenArmError, Result};
+
+/// Velocity (rad/s) below which the gripper motor is considered stalled.
+const STALL_VELOCITY_EPS: f64 = 0.1;
+/// Fraction of the commanded torque limit that counts as "pinned at force".
+const FORCE_LIMIT_FRACTION: f64 = 0.8;
+/// Motor position tolerance (rad) for being fully closed.
+const CLOSED_POSITION_EPS: f64 = 0.05;
+/// Anti-windup bound on the PID integral term.
+const PID_INTEGRAL_LIMIT: f64 = 10.0;
+
+/// PID gain set for the host-side gripper controller.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PidGains {
+    /// Proportional gain.
+    pub kp: f64,
+    /// Integral gain.
+    pub ki: f64,
+    /// Derivative gain.
+    pub kd: f64,
+}
+
+impl PidGains {
+    /// Create a new gain set.
+    pub fn new(kp: f64, ki: f64, kd: f64) -> Self {
+        Self { kp, ki, kd }
+    }
+}
+
+/// Which quantity the host-side PID loop regulates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PidTarget {
+    /// Servo the motor to a target motor position (rad), emitting MIT torque.
+    Position(f64),
+    /// Regulate grasp torque (per-unit), emitting a position-force command.
+    Grasp(f64),
+}
+
+/// Mutable state for the host-side PID loop, carried across [`pid_step`]s.
+///
+/// [`pid_step`]: GripperComponent::pid_step
+#[derive(Debug, Clone, Copy)]
+struct PidState {
+    gains: PidGains,
+    target: PidTarget,
+    integral: f64,
+    prev_error: f64,
+}
+
+impl PidState {
+    /// Advance the controller by one tick and return the control effort for
+    /// `error` over `dt` seconds, integrating with anti-windup clamping.
+    fn update(&mut self, error: f64, dt: f64) -> f64 {
+        self.integral =
+            (self.integral + error * dt).clamp(-PID_INTEGRAL_LIMIT, PID_INTEGRAL_LIMIT);
+        let derivative = if dt > 0.0 {
+            (error - self.prev_error) / dt
+        } else {
+            0.0
+        };
+        self.prev_error = error;
+        self.gains.kp * error + self.gains.ki * self.integral + self.gains.kd * derivative
+    }
+}
+
+/// Outcome of a stall-based grasp detection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GraspState {
+    /// Motion stalled against an object before fully closing.
+    Grasped,
+    /// Gripper closed fully with nothing in between.
+    Closed,
+    /// Still moving when the settle window elapsed.
+    Moving,
+}
+
+/// Result of [`GripperComponent::grasp_and_detect`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraspResult {
+    /// Detected grasp state.
+    pub state: GraspState,
+    /// Final gripper position (0.0 = closed, 1.0 = open).
+    pub position: f64,
+}
 
 /// Gripper component for single-motor gripper control.
 pub struct GripperComponent {
@@ -21,6 +105,8 @@ pub struct GripperComponent {
     // Default limits
     default_speed_rad_s: f64,
     default_torque_pu: f64,
+    // Host-side PID loop state (None until a loop is started)
+    pid: Option<PidState>,
 }
 
 impl GripperComponent {
@@ -35,6 +121,7 @@ impl GripperComponent {
             motor_closed_position: 0.0,
             default_speed_rad_s: 5.0,
             default_torque_pu: 0.3,
+            pid: None,
         }
     }
 
@@ -54,7 +141,23 @@ impl GripperComponent {
         let gripper_range = self.gripper_open_position - self.gripper_closed_position;
         let motor_range = self.motor_open_position - self.motor_closed_position;
         let normalized = (gripper_position - self.gripper_closed_position) / gripper_range;
-        self.motor_closed_position + normalized * motor_range
+        let motor_position = self.motor_closed_position + normalized * motor_range;
+        // Clamp to the physical travel so an out-of-range [0,1] command can't
+        // drive the motor past its open/closed endpoints.
+        let (lo, hi) = if self.motor_closed_position <= self.motor_open_position {
+            (self.motor_closed_position, self.motor_open_position)
+        } else {
+            (self.motor_open_position, self.motor_closed_position)
+        };
+        motor_position.clamp(lo, hi)
+    }
+
+    /// Convert motor position in radians back to gripper position [0,1].
+    fn motor_to_gripper_position(&self, motor_position: f64) -> f64 {
+        let gripper_range = self.gripper_open_position - self.gripper_closed_position;
+        let motor_range = self.motor_open_position - self.motor_closed_position;
+        let normalized = (motor_position - self.motor_closed_position) / motor_range;
+        self.gripper_closed_position + normalized * gripper_range
     }
 
     /// Initialize gripper motor.
@@ -127,6 +230,26 @@ impl GripperComponent {
         self.default_torque_pu = torque_pu;
     }
 
+    /// Configure the gripper↔motor position mapping.
+    ///
+    /// Overrides the open/closed/grasp gripper positions and the corresponding
+    /// open/closed motor positions used by the linear interpolation.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_position_mapping(
+        &mut self,
+        gripper_open_position: f64,
+        gripper_closed_position: f64,
+        gripper_grasp_position: f64,
+        motor_open_position: f64,
+        motor_closed_position: f64,
+    ) {
+        self.gripper_open_position = gripper_open_position;
+        self.gripper_closed_position = gripper_closed_position;
+        self.gripper_grasp_position = gripper_grasp_position;
+        self.motor_open_position = motor_open_position;
+        self.motor_closed_position = motor_closed_position;
+    }
+
     /// Open the gripper using MIT control.
     pub fn open(&self, kp: f64, kd: f64) -> Result<()> {
         let motor_pos = self.gripper_to_motor_position(self.gripper_open_position);
@@ -184,6 +307,50 @@ impl GripperComponent {
         self.inner.posforce_control_one(0, &param)
     }
 
+    /// Set gripper position from a typed `Ratio` (enabled by the `units`
+    /// feature). The ratio is normalized to the raw `[0, 1]` gripper position
+    /// used by [`set_position`](Self::set_position).
+    #[cfg(feature = "units")]
+    pub fn set_position_typed(
+        &self,
+        position: uom::si::f64::Ratio,
+        speed: Option<f64>,
+        torque: Option<f64>,
+    ) -> Result<()> {
+        self.set_position(position.get::<uom::si::ratio::ratio>(), speed, torque, false)
+    }
+
+    /// Set gripper position from a typed `Ratio` with typed speed and torque
+    /// limits (enabled by the `units` feature).
+    #[cfg(feature = "units")]
+    pub fn set_position_with_limits_typed(
+        &self,
+        position: uom::si::f64::Ratio,
+        speed: uom::si::f64::AngularVelocity,
+        torque: uom::si::f64::Ratio,
+    ) -> Result<()> {
+        self.set_position(
+            position.get::<uom::si::ratio::ratio>(),
+            Some(speed.get::<uom::si::angular_velocity::radian_per_second>()),
+            Some(torque.get::<uom::si::ratio::ratio>()),
+            false,
+        )
+    }
+
+    /// Grasp with a typed torque limit and approach speed (enabled by the
+    /// `units` feature).
+    #[cfg(feature = "units")]
+    pub fn grasp_typed(
+        &self,
+        torque: uom::si::f64::Ratio,
+        speed: uom::si::f64::AngularVelocity,
+    ) -> Result<()> {
+        self.grasp(
+            Some(torque.get::<uom::si::ratio::ratio>()),
+            Some(speed.get::<uom::si::angular_velocity::radian_per_second>()),
+        )
+    }
+
     /// Set gripper position using MIT control (legacy).
     pub fn set_position_mit(&self, position: f64, kp: f64, kd: f64) -> Result<()> {
         let motor_pos = self.gripper_to_motor_position(position);
@@ -213,6 +380,133 @@ impl GripperComponent {
         self.inner.posforce_control_one(0, &param)
     }
 
+    /// Grasp with force control and detect the outcome.
+    ///
+    /// Commands a grasp, then samples the motor feedback over a settle window
+    /// and classifies the result by stall detection: velocity near zero with
+    /// the torque estimate pinned near the commanded force limit and the final
+    /// position short of fully closed means an object was caught; stalling at
+    /// the closed position means the gripper is empty.
+    pub fn grasp_and_detect(
+        &self,
+        torque_pu: Option<f64>,
+        speed_rad_s: Option<f64>,
+        settle_timeout_us: u64,
+    ) -> Result<GraspResult> {
+        let torque_val = torque_pu.unwrap_or(self.default_torque_pu);
+        let speed_val = speed_rad_s.unwrap_or(self.default_speed_rad_s);
+        self.grasp(Some(torque_val), Some(speed_val))?;
+
+        let motor = self.get_motor()?;
+        let closed_motor_pos = self.gripper_to_motor_position(self.gripper_closed_position);
+
+        let deadline = Instant::now() + Duration::from_micros(settle_timeout_us);
+        while Instant::now() < deadline {
+            self.inner.recv_all(2000)?;
+        }
+
+        let position = motor.get_position();
+        let velocity = motor.get_velocity();
+        let torque_pu_est = (motor.get_raw_torque() / motor.motor_type().get_limits().t_max).abs();
+
+        let stalled = velocity.abs() < STALL_VELOCITY_EPS;
+        let at_force_limit = torque_pu_est >= torque_val * FORCE_LIMIT_FRACTION;
+        let short_of_closed = (position - closed_motor_pos).abs() > CLOSED_POSITION_EPS;
+
+        let state = if stalled && at_force_limit && short_of_closed {
+            GraspState::Grasped
+        } else if stalled && !short_of_closed {
+            GraspState::Closed
+        } else {
+            GraspState::Moving
+        };
+
+        Ok(GraspResult {
+            state,
+            position: self.motor_to_gripper_position(position),
+        })
+    }
+
+    /// Start a host-side PID position loop toward a gripper position `[0,1]`.
+    ///
+    /// The loop servos the motor to the mapped motor position with the given
+    /// gains; each [`pid_step`](Self::pid_step) emits a pure-torque MIT command
+    /// (firmware position gains zeroed) so the host owns the loop. Any existing
+    /// PID state is reset.
+    pub fn start_pid_position(&mut self, target: f64, gains: PidGains) {
+        let motor_target = self.gripper_to_motor_position(target);
+        self.pid = Some(PidState {
+            gains,
+            target: PidTarget::Position(motor_target),
+            integral: 0.0,
+            prev_error: 0.0,
+        });
+    }
+
+    /// Start a host-side PID grasp loop regulating to `target_torque` (per-unit).
+    ///
+    /// Each [`pid_step`](Self::pid_step) reads the latest motor torque estimate,
+    /// drives the error through the PID law, and emits a position-force command
+    /// whose force channel is the clamped controller output. Any existing PID
+    /// state is reset.
+    pub fn start_pid_grasp(&mut self, target_torque: f64, gains: PidGains) {
+        self.pid = Some(PidState {
+            gains,
+            target: PidTarget::Grasp(target_torque),
+            integral: 0.0,
+            prev_error: 0.0,
+        });
+    }
+
+    /// Advance the active host-side PID loop by `dt` seconds.
+    ///
+    /// Reads the latest feedback from motor state, computes the control effort,
+    /// and emits the corresponding MIT or position-force command. Returns the
+    /// emitted control effort, or an error if no PID loop is active.
+    pub fn pid_step(&mut self, dt: f64) -> Result<f64> {
+        let mut pid = self
+            .pid
+            .ok_or_else(|| OpenArmError::ConfigError("no active PID loop".to_string()))?;
+        let motor = self.get_motor()?;
+
+        let effort = match pid.target {
+            PidTarget::Position(motor_target) => {
+                let error = motor_target - motor.get_position();
+                let tau = pid.update(error, dt);
+                let param = MITParam {
+                    kp: 0.0,
+                    kd: 0.0,
+                    q: motor_target,
+                    dq: 0.0,
+                    tau,
+                };
+                self.inner.mit_control_one(0, &param)?;
+                tau
+            }
+            PidTarget::Grasp(target_torque) => {
+                let torque_pu_est =
+                    (motor.get_raw_torque() / motor.motor_type().get_limits().t_max).abs();
+                let error = target_torque - torque_pu_est;
+                let force = pid.update(error, dt).clamp(0.0, 1.0);
+                let param = PosForceParam {
+                    q: self.gripper_to_motor_position(self.gripper_grasp_position),
+                    dq: self.default_speed_rad_s,
+                    i: force,
+                };
+                self.inner.posforce_control_one(0, &param)?;
+                force
+            }
+        };
+
+        self.pid = Some(pid);
+        Ok(effort)
+    }
+
+    /// Stop the active host-side PID loop, discarding its integrator state.
+    pub fn stop_pid(&mut self) {
+        self.pid = None;
+    }
+
     /// MIT control for the gripper motor.
     pub fn mit_control_one(&self, index: usize, param: &MITParam) -> Result<()> {
         self.inner.mit_control_one(index, param)
@@ -243,6 +537,38 @@ impl GripperComponent {
         self.inner.posforce_control_all(params)
     }
 
+    /// Write a register by value on one motor, returning the acknowledged
+    /// result.
+    pub fn set_param_one(
+        &self,
+        index: usize,
+        rid: MotorVariable,
+        value: f64,
+        timeout: Duration,
+    ) -> Result<ParamResult> {
+        self.inner.set_param_one(index, rid, value, timeout)
+    }
+
+    /// Write a register by value on all motors.
+    pub fn set_param_all(&self, rid: MotorVariable, value: f64) -> Result<()> {
+        self.inner.set_param_all(rid, value)
+    }
+
+    /// Clear the amplifier fault latch for one motor.
+    pub fn clear_faults_one(&self, index: usize) -> Result<()> {
+        self.inner.clear_faults_one(index)
+    }
+
+    /// Clear the amplifier fault latch for all motors.
+    pub fn clear_faults_all(&self) -> Result<()> {
+        self.inner.clear_faults_all()
+    }
+
+    /// Decode the structured fault status for one motor.
+    pub fn fault_status(&self, index: usize) -> Result<MotorFaultStatus> {
+        self.inner.fault_status(index)
+    }
+
     /// Set control mode.
     pub fn set_control_mode_one(&self, index: usize, mode: ControlMode) -> Result<()> {
         self.inner.set_control_mode_one(index, mode)
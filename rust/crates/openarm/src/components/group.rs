@@ -0,0 +1,168 @@
+//! Transport-agnostic, name-keyed collection of joint motors.
+//!
+//! Where [`OpenArm`](super::OpenArm) owns a live CAN socket and drives the bus
+//! directly, a [`MotorGroup`] only owns an ordered set of [`Motor`]s and acts
+//! as a codec: its batch methods return the [`CANPacket`]s the caller must send,
+//! and [`apply_feedback`](MotorGroup::apply_feedback) folds incoming frames back
+//! into the matching motor's state. This lets callers that already manage their
+//! own transport drive a whole arm from a single object.
+
+use crate::damiao_motor::{
+    CANPacket, CanPacketDecoder, CanPacketEncoder, MITParam, Motor,
+};
+use crate::error::{OpenArmError, Result};
+
+use super::config::{parse_control_mode, parse_motor_type, MotorGroupConfig};
+
+/// An ordered, name-keyed collection of joint motors.
+pub struct MotorGroup {
+    names: Vec<String>,
+    motors: Vec<Motor>,
+}
+
+impl MotorGroup {
+    /// Create an empty group.
+    pub fn new() -> Self {
+        Self {
+            names: Vec::new(),
+            motors: Vec::new(),
+        }
+    }
+
+    /// Append a named joint motor. Order defines the group's joint order.
+    pub fn add_motor(&mut self, name: impl Into<String>, motor: Motor) {
+        self.names.push(name.into());
+        self.motors.push(motor);
+    }
+
+    /// Build a group from a YAML or TOML configuration file describing each
+    /// joint's motor type, CAN ids, control mode, and calibration.
+    pub fn from_config_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let config = MotorGroupConfig::from_path(path)?;
+        let mut group = MotorGroup::new();
+        for joint in &config.joints {
+            let mut motor = Motor::new(
+                parse_motor_type(&joint.motor_type)?,
+                joint.send_can_id,
+                joint.recv_can_id,
+                parse_control_mode(joint.control_mode.as_deref())?,
+            );
+            motor.set_offset(joint.offset);
+            motor.set_reduction_ratio(joint.reduction_ratio);
+            group.add_motor(joint.name.clone(), motor);
+        }
+        Ok(group)
+    }
+
+    /// Number of joints in the group.
+    pub fn motor_count(&self) -> usize {
+        self.motors.len()
+    }
+
+    /// Ordered joint names.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Borrow a joint motor by index.
+    pub fn get_motor(&self, index: usize) -> Result<&Motor> {
+        self.motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))
+    }
+
+    /// Borrow a joint motor by name.
+    pub fn get_motor_by_name(&self, name: &str) -> Result<&Motor> {
+        self.names
+            .iter()
+            .position(|n| n == name)
+            .map(|i| &self.motors[i])
+            .ok_or_else(|| OpenArmError::ConfigError(format!("unknown joint '{}'", name)))
+    }
+
+    /// Enable commands for every joint, in joint order.
+    pub fn enable_all(&self) -> Vec<CANPacket> {
+        self.motors
+            .iter()
+            .map(CanPacketEncoder::create_enable_command)
+            .collect()
+    }
+
+    /// Disable commands for every joint, in joint order.
+    pub fn disable_all(&self) -> Vec<CANPacket> {
+        self.motors
+            .iter()
+            .map(CanPacketEncoder::create_disable_command)
+            .collect()
+    }
+
+    /// Set-zero commands for every joint, in joint order.
+    pub fn set_zero_all(&self) -> Vec<CANPacket> {
+        self.motors
+            .iter()
+            .map(CanPacketEncoder::create_set_zero_command)
+            .collect()
+    }
+
+    /// MIT control commands for the whole group, in joint order.
+    ///
+    /// Each input slice is indexed by joint and must have length
+    /// [`motor_count`](Self::motor_count); otherwise a
+    /// [`ConfigError`](OpenArmError::ConfigError) is returned.
+    pub fn mit_control(
+        &self,
+        positions: &[f64],
+        velocities: &[f64],
+        torques: &[f64],
+        kps: &[f64],
+        kds: &[f64],
+    ) -> Result<Vec<CANPacket>> {
+        let n = self.motors.len();
+        for (label, slice) in [
+            ("positions", positions.len()),
+            ("velocities", velocities.len()),
+            ("torques", torques.len()),
+            ("kps", kps.len()),
+            ("kds", kds.len()),
+        ] {
+            if slice != n {
+                return Err(OpenArmError::ConfigError(format!(
+                    "{} has {} entries but the group has {} joints",
+                    label, slice, n
+                )));
+            }
+        }
+
+        Ok(self
+            .motors
+            .iter()
+            .enumerate()
+            .map(|(i, motor)| {
+                let param = MITParam {
+                    kp: kps[i],
+                    kd: kds[i],
+                    q: positions[i],
+                    dq: velocities[i],
+                    tau: torques[i],
+                };
+                CanPacketEncoder::create_mit_control_command(motor, &param)
+            })
+            .collect())
+    }
+
+    /// Route an incoming feedback frame to the joint whose `recv_can_id`
+    /// matches, updating its state. Returns `true` when a joint consumed the
+    /// frame.
+    pub fn apply_feedback(&self, recv_can_id: u32, data: &[u8]) -> bool {
+        match self.motors.iter().find(|m| m.recv_can_id() == recv_can_id) {
+            Some(motor) => CanPacketDecoder::parse_and_update_motor_state(motor, data),
+            None => false,
+        }
+    }
+}
+
+impl Default for MotorGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,12 +1,23 @@
 //! High-level components for OpenArm control.
 
 pub mod arm;
+pub mod config;
 pub mod gripper;
+pub mod group;
+pub mod multiarm;
 pub mod openarm;
+pub mod safety;
 
-pub use arm::ArmComponent;
-pub use gripper::GripperComponent;
-pub use openarm::OpenArm;
+pub use arm::{ArmComponent, Trajectory, TrajectoryEvent};
+pub use config::{
+    GripperConfig, JointConfig, MotorConfig, MotorGroupConfig, MotorGroupJointConfig,
+    OpenArmConfig, RobotConfig, RobotGripperConfig,
+};
+pub use gripper::{GraspResult, GraspState, GripperComponent, PidGains};
+pub use group::MotorGroup;
+pub use multiarm::MultiArm;
+pub use openarm::{OpenArm, OpenArmInterface};
+pub use safety::{JointLimits, LimitPolicy, SafetySupervisor};
 
 #[cfg(feature = "remote")]
 pub use arm::AnyArmComponent;
@@ -0,0 +1,150 @@
+//! Coordinated multi-arm (bimanual) orchestrator.
+//!
+//! [`MultiArm`] owns several independent arms — any mix of local
+//! [`OpenArm`](super::OpenArm) and remote
+//! [`RemoteOpenArm`](super::openarm::RemoteOpenArm) instances — behind the
+//! shared [`OpenArmInterface`] surface, and drives them as one handle. Group
+//! operations fan a command out across every arm; the combined state read
+//! (`refresh_all` + `recv_all`) issues requests to all arms first and then
+//! collects responses under a single shared deadline, so per-arm latency
+//! differences (a local `CANSocket` versus an arm reached over xoq P2P) do not
+//! desynchronize the merged snapshot.
+
+use std::time::{Duration, Instant};
+
+use crate::damiao_motor::{CallbackMode, MotorVariable};
+use crate::error::{OpenArmError, Result};
+
+use super::openarm::OpenArmInterface;
+
+/// A named set of arms driven together as a single unit.
+///
+/// Arms are stored in insertion order; that order defines how group results
+/// (for example the per-arm frame counts returned by [`recv_all`](Self::recv_all))
+/// line up with the arms that produced them.
+pub struct MultiArm {
+    names: Vec<String>,
+    arms: Vec<Box<dyn OpenArmInterface + Send>>,
+}
+
+impl MultiArm {
+    /// Create an empty orchestrator.
+    pub fn new() -> Self {
+        Self {
+            names: Vec::new(),
+            arms: Vec::new(),
+        }
+    }
+
+    /// Register a named arm. The arm may be backed by any transport, since the
+    /// orchestrator only depends on the [`OpenArmInterface`] surface.
+    pub fn add_arm(&mut self, name: impl Into<String>, arm: Box<dyn OpenArmInterface + Send>) {
+        self.names.push(name.into());
+        self.arms.push(arm);
+    }
+
+    /// Number of registered arms.
+    pub fn arm_count(&self) -> usize {
+        self.arms.len()
+    }
+
+    /// Registered arm names, in insertion order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Borrow an arm by index.
+    pub fn get_arm(&self, index: usize) -> Result<&(dyn OpenArmInterface + Send)> {
+        self.arms
+            .get(index)
+            .map(|a| a.as_ref())
+            .ok_or(OpenArmError::IndexOutOfRange(index))
+    }
+
+    /// Enable every motor on every arm.
+    pub fn enable_all(&self) -> Result<()> {
+        for arm in &self.arms {
+            arm.enable_all()?;
+        }
+        Ok(())
+    }
+
+    /// Disable every motor on every arm.
+    pub fn disable_all(&self) -> Result<()> {
+        for arm in &self.arms {
+            arm.disable_all()?;
+        }
+        Ok(())
+    }
+
+    /// Set the zero position for every motor on every arm.
+    pub fn set_zero_all(&self) -> Result<()> {
+        for arm in &self.arms {
+            arm.set_zero_all()?;
+        }
+        Ok(())
+    }
+
+    /// Query a parameter from every motor on every arm.
+    pub fn query_param_all(&self, rid: MotorVariable) -> Result<()> {
+        for arm in &self.arms {
+            arm.query_param_all(rid)?;
+        }
+        Ok(())
+    }
+
+    /// Set the callback mode on every arm's device collection.
+    pub fn set_callback_mode_all(&self, mode: CallbackMode) {
+        for arm in &self.arms {
+            arm.set_callback_mode_all(mode);
+        }
+    }
+
+    /// Issue refresh requests to every arm.
+    ///
+    /// This only puts the request frames on each bus; pair it with
+    /// [`recv_all`](Self::recv_all) to collect the responses, or use
+    /// [`refresh_and_recv_all`](Self::refresh_and_recv_all) to do both under one
+    /// shared deadline.
+    pub fn refresh_all(&self) -> Result<()> {
+        for arm in &self.arms {
+            arm.refresh_all()?;
+        }
+        Ok(())
+    }
+
+    /// Collect responses from every arm under a single shared deadline.
+    ///
+    /// The deadline is computed once from `total_timeout_us`; each arm is given
+    /// whatever time remains until that instant to receive its first frame, so
+    /// slow arms cannot push the barrier out past it. Returns the frame count
+    /// collected per arm, in registration order.
+    pub fn recv_all(&self, total_timeout_us: u64) -> Result<Vec<usize>> {
+        let deadline = Instant::now() + Duration::from_micros(total_timeout_us);
+        let mut counts = Vec::with_capacity(self.arms.len());
+        for arm in &self.arms {
+            let remaining = deadline
+                .saturating_duration_since(Instant::now())
+                .as_micros() as u64;
+            counts.push(arm.recv_all(remaining)?);
+        }
+        Ok(counts)
+    }
+
+    /// Refresh and collect state across every arm with a shared deadline.
+    ///
+    /// All refresh requests are dispatched first so every bus is busy in
+    /// parallel, then responses are gathered under one deadline derived from
+    /// `total_timeout_us`. The returned per-arm frame counts line up with
+    /// [`names`](Self::names).
+    pub fn refresh_and_recv_all(&self, total_timeout_us: u64) -> Result<Vec<usize>> {
+        self.refresh_all()?;
+        self.recv_all(total_timeout_us)
+    }
+}
+
+impl Default for MultiArm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
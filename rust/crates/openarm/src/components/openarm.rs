@@ -1,13 +1,65 @@
 //! Main OpenArm orchestrator class.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
 
 use crate::canbus::{CANDeviceCollection, CANSocket, MotorDeviceCan};
-use crate::damiao_motor::{CallbackMode, ControlMode, Motor, MotorType, MotorVariable};
+use crate::damiao_motor::{
+    CallbackMode, CanPacketEncoder, ControlMode, MITParam, Motor, MotorState, MotorType,
+    MotorVariable, PosForceParam, PosVelParam, VelParam,
+};
 use crate::error::{OpenArmError, Result};
 
 use super::arm::ArmComponent;
 use super::gripper::GripperComponent;
+use super::safety::SafetySupervisor;
+
+/// Common orchestration API shared by local and remote OpenArm variants.
+///
+/// `OpenArm` and [`RemoteOpenArm`] differ only in the transport they hold
+/// (local `CANSocket`/`CANDeviceCollection` vs. the `Any*` remote variants);
+/// the high-level control flow is identical. This trait captures that shared
+/// surface so downstream code can be written once over either transport:
+///
+/// ```no_run
+/// use openarm::{OpenArmInterface, Result};
+///
+/// fn run<T: OpenArmInterface>(arm: &mut T) -> Result<()> {
+///     arm.enable_all()?;
+///     arm.refresh_all()?;
+///     arm.disable_all()
+/// }
+/// ```
+pub trait OpenArmInterface {
+    /// Enable all motors (arm and gripper).
+    fn enable_all(&self) -> Result<()>;
+
+    /// Disable all motors (arm and gripper).
+    fn disable_all(&self) -> Result<()>;
+
+    /// Set zero position for all motors.
+    fn set_zero_all(&self) -> Result<()>;
+
+    /// Refresh state for all motors.
+    fn refresh_all(&self) -> Result<()>;
+
+    /// Refresh state for one motor (arm only).
+    fn refresh_one(&self, index: usize) -> Result<()>;
+
+    /// Query parameter for all motors.
+    fn query_param_all(&self, rid: MotorVariable) -> Result<()>;
+
+    /// Set callback mode for all devices.
+    fn set_callback_mode_all(&self, mode: CallbackMode);
+
+    /// Receive all available frames with timeout for first frame.
+    fn recv_all(&self, first_timeout_us: u64) -> Result<usize>;
+
+    /// Check if CAN-FD is enabled.
+    fn enable_fd(&self) -> bool;
+}
 
 /// Main OpenArm orchestrator class.
 ///
@@ -19,6 +71,22 @@ pub struct OpenArm {
     arm: ArmComponent,
     gripper: GripperComponent,
     enable_fd: bool,
+    arm_joint_names: Vec<String>,
+    /// Latest per-device state snapshot, in `arm` then `gripper` order,
+    /// published by the background streaming thread (empty until the first
+    /// cycle completes; the last snapshot is kept after the stream stops).
+    snapshot: Arc<Mutex<Vec<MotorState>>>,
+    /// Handle to the running streaming thread, if any.
+    stream: Option<StreamHandle>,
+    /// Command safety gatekeeper consulted before motion commands are
+    /// dispatched via the supervised `set_*` helpers.
+    supervisor: SafetySupervisor,
+}
+
+/// Handle to a running background telemetry thread.
+struct StreamHandle {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
 }
 
 impl OpenArm {
@@ -40,9 +108,142 @@ impl OpenArm {
             arm,
             gripper,
             enable_fd,
+            arm_joint_names: Vec::new(),
+            snapshot: Arc::new(Mutex::new(Vec::new())),
+            stream: None,
+            supervisor: SafetySupervisor::new(super::safety::LimitPolicy::default()),
         })
     }
 
+    /// Build an OpenArm from a YAML or TOML configuration file.
+    ///
+    /// The file describes the FD flag, the ordered list of arm joint motors
+    /// (each with an optional zero `offset`), and an optional gripper section.
+    /// The CAN interface is supplied by the `can_interface` argument rather than
+    /// the file, so one layout file can be reused across buses; a
+    /// `can_interface` key in the file is ignored. Joint names recorded in the
+    /// config become available for name-based lookup via
+    /// [`arm_joint_index`](Self::arm_joint_index).
+    pub fn from_config(
+        path: impl AsRef<std::path::Path>,
+        can_interface: impl Into<String>,
+    ) -> Result<Self> {
+        use super::config::{parse_control_mode, parse_motor_type, OpenArmConfig};
+        use super::safety::JointLimits;
+        use crate::damiao_motor::Limit;
+
+        let config = OpenArmConfig::from_path(path)?;
+        let mut openarm = OpenArm::new(can_interface.into(), config.enable_fd)?;
+
+        let mut joint_limits = Vec::with_capacity(config.motors.len());
+        for motor in &config.motors {
+            let motor_type = parse_motor_type(&motor.motor_type)?;
+            let control_mode = parse_control_mode(motor.control_mode.as_deref())?;
+            let mut m = Motor::new(motor_type, motor.send_can_id, motor.recv_can_id, control_mode);
+            if motor.offset != 0.0 {
+                m.set_offset(motor.offset);
+            }
+            let device = Arc::new(Mutex::new(MotorDeviceCan::new(m.clone())));
+            openarm
+                .master_collection
+                .register_device_internal(Arc::clone(&device));
+            openarm.arm.add_motor_device(m, device);
+            openarm.arm_joint_names.push(motor.name.clone());
+
+            joint_limits.push(JointLimits {
+                position: Limit::new(
+                    motor.position_min.unwrap_or(f64::NEG_INFINITY),
+                    motor.position_max.unwrap_or(f64::INFINITY),
+                ),
+                max_velocity: motor.max_velocity.unwrap_or(f64::INFINITY),
+                max_torque: motor.max_torque.unwrap_or(f64::INFINITY),
+            });
+        }
+        openarm.supervisor =
+            SafetySupervisor::with_limits(joint_limits, openarm.supervisor.policy());
+
+        if let Some(gripper) = &config.gripper {
+            openarm.init_gripper_motor(
+                parse_motor_type(&gripper.motor_type)?,
+                gripper.send_can_id,
+                gripper.recv_can_id,
+                parse_control_mode(gripper.control_mode.as_deref())?,
+            )?;
+            if gripper.speed_limit.is_some() || gripper.torque_limit.is_some() {
+                // An unset value keeps the gripper's built-in default (5 rad/s, 0.3 pu).
+                openarm.gripper_mut().set_limit(
+                    gripper.speed_limit.unwrap_or(5.0),
+                    gripper.torque_limit.unwrap_or(0.3),
+                );
+            }
+        }
+
+        Ok(openarm)
+    }
+
+    /// Build a fully populated OpenArm from a robot configuration file.
+    ///
+    /// Reads the joint list and gripper mapping from a YAML or TOML file,
+    /// registers every arm joint and the gripper motor in the shared
+    /// collection, and applies the gripper's open/closed/grasp position mapping
+    /// and default speed/torque limits.
+    pub fn from_robot_config(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        use super::config::{parse_control_mode, parse_motor_type, RobotConfig};
+
+        let config = RobotConfig::from_path(path)?;
+        let mut openarm = OpenArm::new(config.can_interface, config.enable_fd)?;
+
+        let mut motor_types = Vec::with_capacity(config.joints.len());
+        let mut send_can_ids = Vec::with_capacity(config.joints.len());
+        let mut recv_can_ids = Vec::with_capacity(config.joints.len());
+        let mut control_modes = Vec::with_capacity(config.joints.len());
+        for joint in &config.joints {
+            motor_types.push(parse_motor_type(&joint.motor_type)?);
+            send_can_ids.push(joint.send_can_id);
+            recv_can_ids.push(joint.recv_can_id);
+            control_modes.push(parse_control_mode(joint.control_mode.as_deref())?);
+        }
+        openarm.init_arm_motors(
+            &motor_types,
+            &send_can_ids,
+            &recv_can_ids,
+            Some(&control_modes),
+        )?;
+
+        if let Some(gripper) = &config.gripper {
+            openarm.init_gripper_motor(
+                parse_motor_type(&gripper.motor_type)?,
+                gripper.send_can_id,
+                gripper.recv_can_id,
+                parse_control_mode(gripper.control_mode.as_deref())?,
+            )?;
+            let g = openarm.gripper_mut();
+            g.set_position_mapping(
+                gripper.gripper_open_position,
+                gripper.gripper_closed_position,
+                gripper.gripper_grasp_position,
+                gripper.motor_open_position,
+                gripper.motor_closed_position,
+            );
+            g.set_limit(gripper.default_speed_rad_s, gripper.default_torque_pu);
+        }
+
+        Ok(openarm)
+    }
+
+    /// Look up an arm joint index by its configured name.
+    ///
+    /// Returns `None` when the arm was not built from a config or the name is
+    /// unknown.
+    pub fn arm_joint_index(&self, name: &str) -> Option<usize> {
+        self.arm_joint_names.iter().position(|n| n == name)
+    }
+
+    /// Arm joint names in index order, as recorded from the configuration.
+    pub fn arm_joint_names(&self) -> &[String] {
+        &self.arm_joint_names
+    }
+
     /// Initialize arm motors.
     pub fn init_arm_motors(
         &mut self,
@@ -135,58 +336,172 @@ impl OpenArm {
         Arc::clone(&self.socket)
     }
 
-    /// Enable all motors (arm and gripper).
-    pub fn enable_all(&self) -> Result<()> {
+    /// Start a background thread that refreshes and receives state for every
+    /// motor every `period_us` microseconds, publishing the latest full-arm
+    /// snapshot (readable via [`latest_snapshot`](Self::latest_snapshot)).
+    ///
+    /// The thread owns `Arc` clones of the master collection and the motor
+    /// list, so it shares the same socket and per-motor state as the foreground
+    /// object. Because `CANSocket` is serialized behind a mutex, interleaving
+    /// manual `refresh_*`/`recv_all` calls with a running stream is safe but
+    /// will compete for the bus; prefer one or the other. Calling
+    /// `start_streaming` while a stream is already running is a no-op.
+    pub fn start_streaming(&mut self, period_us: u64) {
+        if self.stream.is_some() {
+            return;
+        }
+
+        let mut motors = self.arm.get_motors();
+        motors.extend(self.gripper.get_motors());
+        let collection = Arc::clone(&self.master_collection);
+        let snapshot = Arc::clone(&self.snapshot);
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let period = Duration::from_micros(period_us);
+
+        let handle = std::thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                for motor in &motors {
+                    let packet = CanPacketEncoder::create_refresh_command(motor);
+                    let _ = collection.send_packet(packet.send_can_id, &packet.data);
+                }
+                let _ = collection.recv_all(period_us);
+
+                let states: Vec<MotorState> = motors.iter().map(|m| m.get_state()).collect();
+                if let Ok(mut guard) = snapshot.lock() {
+                    *guard = states;
+                }
+
+                std::thread::sleep(period);
+            }
+        });
+
+        self.stream = Some(StreamHandle { stop, handle });
+    }
+
+    /// Stop the background streaming thread, if one is running, and wait for it
+    /// to finish.
+    pub fn stop_streaming(&mut self) {
+        if let Some(stream) = self.stream.take() {
+            stream.stop.store(true, Ordering::Relaxed);
+            let _ = stream.handle.join();
+        }
+    }
+
+    /// Check whether the background streaming thread is running.
+    pub fn is_streaming(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    /// Read the most recent full-arm state snapshot published by the streaming
+    /// thread, in `arm` then `gripper` order. Empty until the first cycle
+    /// completes.
+    pub fn latest_snapshot(&self) -> Vec<MotorState> {
+        self.snapshot.lock().unwrap().clone()
+    }
+
+    /// The command safety supervisor.
+    pub fn supervisor(&self) -> &SafetySupervisor {
+        &self.supervisor
+    }
+
+    /// Mutable access to the safety supervisor, for adjusting limits or the
+    /// clamp-vs-reject policy after construction.
+    pub fn supervisor_mut(&mut self) -> &mut SafetySupervisor {
+        &mut self.supervisor
+    }
+
+    /// Send a supervised MIT command to one arm joint.
+    ///
+    /// The command is checked against the joint's configured safety limits
+    /// before it is dispatched: out-of-range values are clamped or the call
+    /// returns [`OpenArmError::LimitViolation`] depending on the supervisor's
+    /// [`LimitPolicy`](super::safety::LimitPolicy).
+    pub fn set_mit_control_one(&self, index: usize, param: &MITParam) -> Result<()> {
+        let checked = self.supervisor.check_mit(index, param)?;
+        self.arm.mit_control_one(index, &checked)
+    }
+
+    /// Send supervised MIT commands to all arm joints, one [`MITParam`] per
+    /// joint in index order.
+    pub fn set_mit_control_all(&self, params: &[MITParam]) -> Result<()> {
+        let checked = params
+            .iter()
+            .enumerate()
+            .map(|(i, p)| self.supervisor.check_mit(i, p))
+            .collect::<Result<Vec<_>>>()?;
+        self.arm.mit_control_all(&checked)
+    }
+
+    /// Send a supervised position-velocity command to one arm joint.
+    pub fn set_posvel_control_one(&self, index: usize, param: &PosVelParam) -> Result<()> {
+        let checked = self.supervisor.check_posvel(index, param)?;
+        self.arm.posvel_control_one(index, &checked)
+    }
+
+    /// Send a supervised velocity command to one arm joint.
+    pub fn set_vel_control_one(&self, index: usize, param: &VelParam) -> Result<()> {
+        let checked = self.supervisor.check_vel(index, param)?;
+        self.arm.vel_control_one(index, &checked)
+    }
+
+    /// Send a supervised position-force command to one arm joint.
+    pub fn set_posforce_control_one(&self, index: usize, param: &PosForceParam) -> Result<()> {
+        let checked = self.supervisor.check_posforce(index, param)?;
+        self.arm.posforce_control_one(index, &checked)
+    }
+}
+
+impl Drop for OpenArm {
+    fn drop(&mut self) {
+        self.stop_streaming();
+    }
+}
+
+impl OpenArmInterface for OpenArm {
+    fn enable_all(&self) -> Result<()> {
         self.arm.enable_all()?;
         self.gripper.enable_all()?;
         Ok(())
     }
 
-    /// Disable all motors (arm and gripper).
-    pub fn disable_all(&self) -> Result<()> {
+    fn disable_all(&self) -> Result<()> {
         self.arm.disable_all()?;
         self.gripper.disable_all()?;
         Ok(())
     }
 
-    /// Set zero position for all motors.
-    pub fn set_zero_all(&self) -> Result<()> {
+    fn set_zero_all(&self) -> Result<()> {
         self.arm.set_zero_all()?;
         self.gripper.set_zero_all()?;
         Ok(())
     }
 
-    /// Refresh state for all motors.
-    pub fn refresh_all(&self) -> Result<()> {
+    fn refresh_all(&self) -> Result<()> {
         self.arm.refresh_all()?;
         self.gripper.refresh_all()?;
         Ok(())
     }
 
-    /// Refresh state for one motor (arm only).
-    pub fn refresh_one(&self, index: usize) -> Result<()> {
+    fn refresh_one(&self, index: usize) -> Result<()> {
         self.arm.refresh_one(index)
     }
 
-    /// Query parameter for all motors.
-    pub fn query_param_all(&self, rid: MotorVariable) -> Result<()> {
+    fn query_param_all(&self, rid: MotorVariable) -> Result<()> {
         self.arm.query_param_all(rid)?;
         self.gripper.query_param_all(rid)?;
         Ok(())
     }
 
-    /// Set callback mode for all devices.
-    pub fn set_callback_mode_all(&self, mode: CallbackMode) {
+    fn set_callback_mode_all(&self, mode: CallbackMode) {
         self.master_collection.set_callback_mode_all(mode);
     }
 
-    /// Receive all available frames with timeout for first frame.
-    pub fn recv_all(&self, first_timeout_us: u64) -> Result<usize> {
+    fn recv_all(&self, first_timeout_us: u64) -> Result<usize> {
         self.master_collection.recv_all(first_timeout_us)
     }
 
-    /// Check if CAN-FD is enabled.
-    pub fn enable_fd(&self) -> bool {
+    fn enable_fd(&self) -> bool {
         self.enable_fd
     }
 }
@@ -227,6 +542,32 @@ impl RemoteOpenArm {
             .map_err(|e| OpenArmError::SocketError(format!("Failed to connect to remote: {}", e)))?;
 
         let socket = crate::canbus::AnyCANSocket::Remote(remote_socket);
+        Ok(Self::from_socket(socket, enable_fd))
+    }
+
+    /// Create a RemoteOpenArm backed by a software-simulated bus.
+    ///
+    /// No SocketCAN interface or xoq server is required: each motor registered
+    /// via [`init_arm_motors`](Self::init_arm_motors) /
+    /// [`init_gripper_motor`](Self::init_gripper_motor) is modelled in software,
+    /// so the full control stack can be driven in CI or on non-Linux hosts.
+    pub fn simulated(enable_fd: bool) -> Self {
+        Self::from_socket(crate::canbus::AnyCANSocket::simulated(), enable_fd)
+    }
+
+    /// Create two `RemoteOpenArm`s connected by an in-memory loopback bus.
+    ///
+    /// Unlike [`simulated`](Self::simulated), which models motor kinematics,
+    /// this wires the raw frames a caller sends on one stack straight to the
+    /// other, so the real encode/dispatch path can be exercised end to end in
+    /// a test with no kernel socket or xoq server involved.
+    pub fn virtual_pair(enable_fd: bool) -> (Self, Self) {
+        let (a, b) = crate::canbus::AnyCANSocket::virtual_pair();
+        (Self::from_socket(a, enable_fd), Self::from_socket(b, enable_fd))
+    }
+
+    /// Assemble the components around an already-opened socket.
+    fn from_socket(socket: crate::canbus::AnyCANSocket, enable_fd: bool) -> Self {
         let socket_arc = Arc::new(Mutex::new(socket));
 
         let master_collection = Arc::new(crate::canbus::AnyCANDeviceCollection::from_socket_arc(
@@ -237,13 +578,13 @@ impl RemoteOpenArm {
         let gripper =
             super::gripper::AnyGripperComponent::from_collection(Arc::clone(&master_collection));
 
-        Ok(Self {
+        Self {
             socket: socket_arc,
             master_collection,
             arm,
             gripper,
             enable_fd,
-        })
+        }
     }
 
     /// Initialize arm motors.
@@ -276,6 +617,7 @@ impl RemoteOpenArm {
             .zip(recv_can_ids.iter().zip(modes.iter()))
         {
             let motor = Motor::new(*motor_type, *send_id, *recv_id, *mode);
+            self.socket.lock().unwrap().register_simulated_motor(&motor);
             let device = Arc::new(Mutex::new(MotorDeviceCan::new(motor.clone())));
 
             self.master_collection
@@ -295,6 +637,7 @@ impl RemoteOpenArm {
         control_mode: ControlMode,
     ) -> Result<()> {
         let motor = Motor::new(motor_type, send_can_id, recv_can_id, control_mode);
+        self.socket.lock().unwrap().register_simulated_motor(&motor);
         let device = Arc::new(Mutex::new(MotorDeviceCan::new(motor.clone())));
 
         self.master_collection
@@ -333,59 +676,53 @@ impl RemoteOpenArm {
     pub fn socket(&self) -> Arc<Mutex<crate::canbus::AnyCANSocket>> {
         Arc::clone(&self.socket)
     }
+}
 
-    /// Enable all motors (arm and gripper).
-    pub fn enable_all(&self) -> Result<()> {
+#[cfg(feature = "remote")]
+impl OpenArmInterface for RemoteOpenArm {
+    fn enable_all(&self) -> Result<()> {
         self.arm.enable_all()?;
         self.gripper.enable_all()?;
         Ok(())
     }
 
-    /// Disable all motors (arm and gripper).
-    pub fn disable_all(&self) -> Result<()> {
+    fn disable_all(&self) -> Result<()> {
         self.arm.disable_all()?;
         self.gripper.disable_all()?;
         Ok(())
     }
 
-    /// Set zero position for all motors.
-    pub fn set_zero_all(&self) -> Result<()> {
+    fn set_zero_all(&self) -> Result<()> {
         self.arm.set_zero_all()?;
         self.gripper.set_zero_all()?;
         Ok(())
     }
 
-    /// Refresh state for all motors.
-    pub fn refresh_all(&self) -> Result<()> {
+    fn refresh_all(&self) -> Result<()> {
         self.arm.refresh_all()?;
         self.gripper.refresh_all()?;
         Ok(())
     }
 
-    /// Refresh state for one motor (arm only).
-    pub fn refresh_one(&self, index: usize) -> Result<()> {
+    fn refresh_one(&self, index: usize) -> Result<()> {
         self.arm.refresh_one(index)
     }
 
-    /// Query parameter for all motors.
-    pub fn query_param_all(&self, rid: MotorVariable) -> Result<()> {
+    fn query_param_all(&self, rid: MotorVariable) -> Result<()> {
         self.arm.query_param_all(rid)?;
         self.gripper.query_param_all(rid)?;
         Ok(())
     }
 
-    /// Set callback mode for all devices.
-    pub fn set_callback_mode_all(&self, mode: CallbackMode) {
+    fn set_callback_mode_all(&self, mode: CallbackMode) {
         self.master_collection.set_callback_mode_all(mode);
     }
 
-    /// Receive all available frames with timeout for first frame.
-    pub fn recv_all(&self, first_timeout_us: u64) -> Result<usize> {
+    fn recv_all(&self, first_timeout_us: u64) -> Result<usize> {
         self.master_collection.recv_all(first_timeout_us)
     }
 
-    /// Check if CAN-FD is enabled.
-    pub fn enable_fd(&self) -> bool {
+    fn enable_fd(&self) -> bool {
         self.enable_fd
     }
 }
@@ -0,0 +1,173 @@
+//! Command safety supervisor for [`OpenArm`](super::OpenArm).
+//!
+//! The supervisor is a single gatekeeping layer consulted by `OpenArm`'s
+//! supervised `set_*` command helpers before the command reaches the master
+//! collection: each such command is validated against the arm's per-joint
+//! limits (position range, maximum velocity, maximum torque/current) first.
+//! Commands sent directly through the arm component or the collection are not
+//! checked. Depending on the selected
+//! [`LimitPolicy`] an out-of-range command is either clamped back into range or
+//! rejected with [`OpenArmError::LimitViolation`]. The limits usually come from
+//! the arm configuration file; a joint with no configured limit is left
+//! unconstrained.
+
+use crate::damiao_motor::{Limit, MITParam, PosForceParam, PosVelParam, VelParam};
+use crate::error::{OpenArmError, Result};
+
+/// How the supervisor reacts to a command that falls outside a joint limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LimitPolicy {
+    /// Clamp the offending value back into the allowed range and proceed.
+    #[default]
+    Clamp,
+    /// Reject the whole command with [`OpenArmError::LimitViolation`].
+    Reject,
+}
+
+/// Motion limits for a single arm joint.
+///
+/// Position is a two-sided range; velocity and torque are symmetric magnitude
+/// bounds applied to both directions. Unset bounds default to infinite, i.e.
+/// unconstrained.
+#[derive(Debug, Clone, Copy)]
+pub struct JointLimits {
+    /// Allowed logical position range (rad).
+    pub position: Limit,
+    /// Maximum absolute logical velocity (rad/s).
+    pub max_velocity: f64,
+    /// Maximum absolute logical torque/current command (Nm or per-unit).
+    pub max_torque: f64,
+}
+
+impl Default for JointLimits {
+    fn default() -> Self {
+        Self {
+            position: Limit::default(),
+            max_velocity: f64::INFINITY,
+            max_torque: f64::INFINITY,
+        }
+    }
+}
+
+impl JointLimits {
+    /// Velocity range as a symmetric [`Limit`].
+    fn velocity_limit(&self) -> Limit {
+        Limit::new(-self.max_velocity, self.max_velocity)
+    }
+
+    /// Torque range as a symmetric [`Limit`].
+    fn torque_limit(&self) -> Limit {
+        Limit::new(-self.max_torque, self.max_torque)
+    }
+}
+
+/// Central safety supervisor holding one [`JointLimits`] per arm joint.
+///
+/// Joints are addressed by their arm index; a command for an index beyond the
+/// configured list is treated as unconstrained so callers can add the
+/// supervisor to an arm whose limits are only partially specified.
+pub struct SafetySupervisor {
+    limits: Vec<JointLimits>,
+    policy: LimitPolicy,
+}
+
+impl SafetySupervisor {
+    /// Create an empty supervisor (no joint limits) with the given policy.
+    pub fn new(policy: LimitPolicy) -> Self {
+        Self {
+            limits: Vec::new(),
+            policy,
+        }
+    }
+
+    /// Create a supervisor from an ordered list of per-joint limits.
+    pub fn with_limits(limits: Vec<JointLimits>, policy: LimitPolicy) -> Self {
+        Self { limits, policy }
+    }
+
+    /// The active out-of-range policy.
+    pub fn policy(&self) -> LimitPolicy {
+        self.policy
+    }
+
+    /// Select the clamp-vs-reject policy.
+    pub fn set_policy(&mut self, policy: LimitPolicy) {
+        self.policy = policy;
+    }
+
+    /// Configured limits in joint order.
+    pub fn limits(&self) -> &[JointLimits] {
+        &self.limits
+    }
+
+    /// Set the limits for one joint, growing the list with unconstrained
+    /// defaults as needed.
+    pub fn set_joint_limits(&mut self, joint: usize, limits: JointLimits) {
+        if joint >= self.limits.len() {
+            self.limits.resize(joint + 1, JointLimits::default());
+        }
+        self.limits[joint] = limits;
+    }
+
+    /// Limits for `joint`, or the unconstrained default when none are set.
+    fn joint(&self, joint: usize) -> JointLimits {
+        self.limits.get(joint).copied().unwrap_or_default()
+    }
+
+    /// Apply `limit` to `value` on `joint`, clamping or rejecting per policy.
+    fn enforce(&self, joint: usize, quantity: &'static str, limit: Limit, value: f64) -> Result<f64> {
+        if limit.contains(value) {
+            return Ok(value);
+        }
+        match self.policy {
+            LimitPolicy::Clamp => Ok(limit.clamp(value)),
+            LimitPolicy::Reject => Err(OpenArmError::LimitViolation {
+                joint,
+                quantity,
+                value,
+                min: limit.min,
+                max: limit.max,
+            }),
+        }
+    }
+
+    /// Validate an MIT command for `joint`, returning the permitted parameters.
+    pub fn check_mit(&self, joint: usize, param: &MITParam) -> Result<MITParam> {
+        let limits = self.joint(joint);
+        Ok(MITParam {
+            q: self.enforce(joint, "position", limits.position, param.q)?,
+            dq: self.enforce(joint, "velocity", limits.velocity_limit(), param.dq)?,
+            tau: self.enforce(joint, "torque", limits.torque_limit(), param.tau)?,
+            kp: param.kp,
+            kd: param.kd,
+        })
+    }
+
+    /// Validate a position-velocity command for `joint`.
+    pub fn check_posvel(&self, joint: usize, param: &PosVelParam) -> Result<PosVelParam> {
+        let limits = self.joint(joint);
+        Ok(PosVelParam {
+            q: self.enforce(joint, "position", limits.position, param.q)?,
+            dq: self.enforce(joint, "velocity", limits.velocity_limit(), param.dq)?,
+        })
+    }
+
+    /// Validate a velocity command for `joint`.
+    pub fn check_vel(&self, joint: usize, param: &VelParam) -> Result<VelParam> {
+        let limits = self.joint(joint);
+        Ok(VelParam {
+            dq: self.enforce(joint, "velocity", limits.velocity_limit(), param.dq)?,
+        })
+    }
+
+    /// Validate a position-force command for `joint`. The current setpoint is
+    /// checked against the torque limit.
+    pub fn check_posforce(&self, joint: usize, param: &PosForceParam) -> Result<PosForceParam> {
+        let limits = self.joint(joint);
+        Ok(PosForceParam {
+            q: self.enforce(joint, "position", limits.position, param.q)?,
+            dq: self.enforce(joint, "velocity", limits.velocity_limit(), param.dq)?,
+            i: self.enforce(joint, "torque", limits.torque_limit(), param.i)?,
+        })
+    }
+}
@@ -2,12 +2,34 @@
 
 use super::constants::*;
 use super::motor::Motor;
+use crate::error::Result;
+
+#[cfg(feature = "units")]
+use uom::si::angle::radian;
+#[cfg(feature = "units")]
+use uom::si::angular_velocity::radian_per_second;
+#[cfg(feature = "units")]
+use uom::si::f64::{Angle, AngularVelocity, Ratio, Torque};
+#[cfg(feature = "units")]
+use uom::si::ratio::ratio;
+#[cfg(feature = "units")]
+use uom::si::torque::newton_meter;
 
 /// Clamp a value to a range.
 fn clamp(value: f64, min: f64, max: f64) -> f64 {
     value.max(min).min(max)
 }
 
+/// Convert radians to degrees, for callers working at the f64 boundary.
+pub fn rad_to_deg(rad: f64) -> f64 {
+    rad.to_degrees()
+}
+
+/// Convert degrees to radians, for callers working at the f64 boundary.
+pub fn deg_to_rad(deg: f64) -> f64 {
+    deg.to_radians()
+}
+
 /// Scale a float to an unsigned integer.
 fn float_to_uint(x: f64, x_min: f64, x_max: f64, bits: u32) -> u32 {
     let span = x_max - x_min;
@@ -23,6 +45,298 @@ fn uint_to_float(x: u32, x_min: f64, x_max: f64, bits: u32) -> f64 {
     x_min + (x as f64 / max_val) * span
 }
 
+/// Wire representation of a motor register value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamWireType {
+    /// 32-bit IEEE-754 float.
+    F32,
+    /// Unsigned 32-bit integer.
+    U32,
+    /// Signed 32-bit integer.
+    I32,
+}
+
+/// A typed motor register value, encoded little-endian on the wire.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParamValue {
+    /// Floating-point register value.
+    F32(f32),
+    /// Unsigned integer register value.
+    U32(u32),
+    /// Signed integer register value.
+    I32(i32),
+}
+
+impl ParamValue {
+    /// Encode the value as the 4 little-endian payload bytes.
+    pub fn to_le_bytes(self) -> [u8; 4] {
+        match self {
+            ParamValue::F32(v) => v.to_le_bytes(),
+            ParamValue::U32(v) => v.to_le_bytes(),
+            ParamValue::I32(v) => v.to_le_bytes(),
+        }
+    }
+
+    /// Decode the 4 little-endian payload bytes according to the register's
+    /// wire type.
+    pub fn from_le_bytes(wire: ParamWireType, bytes: [u8; 4]) -> Self {
+        match wire {
+            ParamWireType::F32 => ParamValue::F32(f32::from_le_bytes(bytes)),
+            ParamWireType::U32 => ParamValue::U32(u32::from_le_bytes(bytes)),
+            ParamWireType::I32 => ParamValue::I32(i32::from_le_bytes(bytes)),
+        }
+    }
+
+    /// Build a value of the register's wire type from an `f64`, rounding to
+    /// the target integer type as needed.
+    pub fn from_f64(wire: ParamWireType, value: f64) -> Self {
+        match wire {
+            ParamWireType::F32 => ParamValue::F32(value as f32),
+            ParamWireType::U32 => ParamValue::U32(value as u32),
+            ParamWireType::I32 => ParamValue::I32(value as i32),
+        }
+    }
+
+    /// Value as an `f64`, for logging or uniform handling.
+    pub fn as_f64(self) -> f64 {
+        match self {
+            ParamValue::F32(v) => v as f64,
+            ParamValue::U32(v) => v as f64,
+            ParamValue::I32(v) => v as f64,
+        }
+    }
+}
+
+impl MotorVariable {
+    /// Wire type used to encode/decode this register.
+    ///
+    /// Identifier and mode registers are integers; everything else is a float.
+    pub fn wire_type(&self) -> ParamWireType {
+        match self {
+            MotorVariable::MST_ID
+            | MotorVariable::ESC_ID
+            | MotorVariable::NPP
+            | MotorVariable::TIMEOUT
+            | MotorVariable::canRateLevel
+            | MotorVariable::canIdLevel
+            | MotorVariable::motorType
+            | MotorVariable::masterid => ParamWireType::U32,
+            MotorVariable::CTRL_MODE => ParamWireType::I32,
+            _ => ParamWireType::F32,
+        }
+    }
+
+    /// Whether this register may be written.
+    ///
+    /// Version, serial-number, and live run/error/angle registers are
+    /// read-only and reject [`set_param`](crate::DMDeviceCollection::set_param_one).
+    pub fn is_writable(&self) -> bool {
+        !matches!(
+            self,
+            MotorVariable::hw_ver
+                | MotorVariable::sw_ver
+                | MotorVariable::SN
+                | MotorVariable::CUR_angle
+                | MotorVariable::run_state
+                | MotorVariable::error_state
+        )
+    }
+}
+
+/// Typed-quantity constructors (enabled by the `units` feature).
+///
+/// These normalize `uom` quantities to the raw units the CAN encoder expects
+/// (rad, rad/s, N·m, per-unit). The existing per-`MotorType` clamping still
+/// runs when the command is encoded, and the bare-`f64` fields remain usable
+/// for callers that do not enable the feature.
+#[cfg(feature = "units")]
+impl MITParam {
+    /// Construct MIT parameters from typed physical quantities.
+    pub fn from_typed(q: Angle, dq: AngularVelocity, tau: Torque, kp: f64, kd: f64) -> Self {
+        Self {
+            kp,
+            kd,
+            q: q.get::<radian>(),
+            dq: dq.get::<radian_per_second>(),
+            tau: tau.get::<newton_meter>(),
+        }
+    }
+}
+
+#[cfg(feature = "units")]
+impl PosVelParam {
+    /// Construct position-velocity parameters from typed physical quantities.
+    pub fn from_typed(q: Angle, dq: AngularVelocity) -> Self {
+        Self {
+            q: q.get::<radian>(),
+            dq: dq.get::<radian_per_second>(),
+        }
+    }
+}
+
+#[cfg(feature = "units")]
+impl PosForceParam {
+    /// Construct position-force parameters from a typed angle, angular
+    /// velocity, and per-unit current ratio.
+    pub fn from_typed(q: Angle, dq: AngularVelocity, i: Ratio) -> Self {
+        Self {
+            q: q.get::<radian>(),
+            dq: dq.get::<radian_per_second>(),
+            i: i.get::<ratio>(),
+        }
+    }
+}
+
+/// Decoded amplifier fault/run state from a DM feedback frame.
+///
+/// DM motors pack a status nibble into the high bits of the first feedback
+/// byte: `0` = disabled, `1` = enabled, and `8..=0xE` encode specific faults.
+/// The raw nibble is kept in [`code`](Self::code) and mapped onto the boolean
+/// fields so callers can detect and recover from amplifier faults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MotorFaultStatus {
+    /// Raw status/error nibble.
+    pub code: u8,
+    /// The amplifier reports itself enabled (code 1).
+    pub enabled: bool,
+    /// Bus over-voltage (code 8).
+    pub overvoltage: bool,
+    /// Bus under-voltage (code 9).
+    pub undervoltage: bool,
+    /// Over-current (code 0xA).
+    pub overcurrent: bool,
+    /// MOSFET over-temperature (code 0xB).
+    pub mos_overtemp: bool,
+    /// Rotor over-temperature (code 0xC).
+    pub rotor_overtemp: bool,
+    /// Communication lost (code 0xD).
+    pub comm_lost: bool,
+    /// Overload (code 0xE).
+    pub overload: bool,
+}
+
+impl MotorFaultStatus {
+    /// Decode the status/error nibble reported in a feedback frame.
+    pub fn from_code(code: u8) -> Self {
+        let mut status = Self {
+            code,
+            ..Default::default()
+        };
+        match code {
+            1 => status.enabled = true,
+            8 => status.overvoltage = true,
+            9 => status.undervoltage = true,
+            0xA => status.overcurrent = true,
+            0xB => status.mos_overtemp = true,
+            0xC => status.rotor_overtemp = true,
+            0xD => status.comm_lost = true,
+            0xE => status.overload = true,
+            _ => {}
+        }
+        status
+    }
+
+    /// Whether the nibble encodes a fault (code `>= 8`) rather than an
+    /// enabled/disabled run state.
+    pub fn is_fault(&self) -> bool {
+        self.code >= 8
+    }
+}
+
+/// Amplifier run/fault state decoded from the status nibble of a DM feedback
+/// frame.
+///
+/// Where [`MotorFaultStatus`] exposes each fault as a boolean flag, this enum
+/// names the exact reported code, mirroring the single-valued feedback decode
+/// used by the CyberGear/robstride SDKs. Codes `0` and `1` are the normal
+/// disabled/enabled run states; `8..=0xE` are faults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MotorError {
+    /// Amplifier disabled (code 0).
+    #[default]
+    Disabled,
+    /// Amplifier enabled and running (code 1).
+    Enabled,
+    /// Bus over-voltage (code 8).
+    Overvoltage,
+    /// Bus under-voltage (code 9).
+    Undervoltage,
+    /// Over-current (code 0xA).
+    Overcurrent,
+    /// MOSFET over-temperature (code 0xB).
+    MosOvertemp,
+    /// Rotor over-temperature (code 0xC).
+    RotorOvertemp,
+    /// Communication lost (code 0xD).
+    CommLoss,
+    /// Overload (code 0xE).
+    Overload,
+}
+
+impl MotorError {
+    /// Decode the status/error nibble reported in a feedback frame. Unknown
+    /// codes fall back to [`MotorError::Disabled`].
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            1 => MotorError::Enabled,
+            8 => MotorError::Overvoltage,
+            9 => MotorError::Undervoltage,
+            0xA => MotorError::Overcurrent,
+            0xB => MotorError::MosOvertemp,
+            0xC => MotorError::RotorOvertemp,
+            0xD => MotorError::CommLoss,
+            0xE => MotorError::Overload,
+            _ => MotorError::Disabled,
+        }
+    }
+
+    /// Whether the amplifier reports itself enabled (code 1).
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, MotorError::Enabled)
+    }
+
+    /// Whether the code names a fault rather than a run state.
+    pub fn is_fault(&self) -> bool {
+        !matches!(self, MotorError::Disabled | MotorError::Enabled)
+    }
+
+    /// Human-readable name of the fault/run state.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MotorError::Disabled => "Disabled",
+            MotorError::Enabled => "Enabled",
+            MotorError::Overvoltage => "Overvoltage",
+            MotorError::Undervoltage => "Undervoltage",
+            MotorError::Overcurrent => "Overcurrent",
+            MotorError::MosOvertemp => "MosOvertemp",
+            MotorError::RotorOvertemp => "RotorOvertemp",
+            MotorError::CommLoss => "CommLoss",
+            MotorError::Overload => "Overload",
+        }
+    }
+}
+
+/// Parameters for velocity (VEL) control mode.
+///
+/// Commands a single target angular velocity in rad/s; the controller runs its
+/// internal velocity loop to reach it. Unlike [`PosVelParam`] there is no
+/// position setpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VelParam {
+    /// Target angular velocity (rad/s).
+    pub dq: f64,
+}
+
+#[cfg(feature = "units")]
+impl VelParam {
+    /// Construct velocity parameters from a typed angular velocity.
+    pub fn from_typed(dq: AngularVelocity) -> Self {
+        Self {
+            dq: dq.get::<radian_per_second>(),
+        }
+    }
+}
+
 /// CAN packet encoder for Damiao motor commands.
 #[derive(Debug, Clone, Default)]
 pub struct CanPacketEncoder;
@@ -57,6 +371,14 @@ impl CanPacketEncoder {
         }
     }
 
+    /// Create clear-error command (reset the amplifier fault latch).
+    pub fn create_clear_error_command(motor: &Motor) -> CANPacket {
+        CANPacket {
+            send_can_id: motor.send_can_id(),
+            data: vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFB],
+        }
+    }
+
     /// Create refresh command (request state update).
     pub fn create_refresh_command(motor: &Motor) -> CANPacket {
         let can_id = motor.send_can_id();
@@ -79,10 +401,10 @@ impl CanPacketEncoder {
     pub fn create_mit_control_command(motor: &Motor, param: &MITParam) -> CANPacket {
         let limits = motor.motor_type().get_limits();
 
-        // Clamp values to limits
-        let q = clamp(param.q, -limits.p_max, limits.p_max);
-        let dq = clamp(param.dq, -limits.v_max, limits.v_max);
-        let tau = clamp(param.tau, -limits.t_max, limits.t_max);
+        // Map logical command values into the raw motor frame, then clamp.
+        let q = clamp(motor.position_to_raw(param.q), -limits.p_max, limits.p_max);
+        let dq = clamp(motor.velocity_to_raw(param.dq), -limits.v_max, limits.v_max);
+        let tau = clamp(motor.torque_to_raw(param.tau), -limits.t_max, limits.t_max);
         let kp = clamp(param.kp, 0.0, 500.0);
         let kd = clamp(param.kd, 0.0, 5.0);
 
@@ -114,8 +436,8 @@ impl CanPacketEncoder {
     pub fn create_posvel_control_command(motor: &Motor, param: &PosVelParam) -> CANPacket {
         let limits = motor.motor_type().get_limits();
 
-        let q = clamp(param.q, -limits.p_max, limits.p_max);
-        let dq = clamp(param.dq, -limits.v_max, limits.v_max);
+        let q = clamp(motor.position_to_raw(param.q), -limits.p_max, limits.p_max);
+        let dq = clamp(motor.velocity_to_raw(param.dq), -limits.v_max, limits.v_max);
 
         // Convert to fixed-point representation
         let q_bytes = (q * 10000.0) as i32;
@@ -137,12 +459,30 @@ impl CanPacketEncoder {
         }
     }
 
+    /// Create velocity control command.
+    pub fn create_vel_control_command(motor: &Motor, param: &VelParam) -> CANPacket {
+        let limits = motor.motor_type().get_limits();
+
+        let dq = clamp(motor.velocity_to_raw(param.dq), -limits.v_max, limits.v_max);
+
+        // Velocity mode carries a single little-endian f32 target velocity.
+        let dq_bytes = (dq as f32).to_le_bytes();
+
+        let mut data = vec![0u8; 8];
+        data[..4].copy_from_slice(&dq_bytes);
+
+        CANPacket {
+            send_can_id: motor.send_can_id() + 0x200,
+            data,
+        }
+    }
+
     /// Create position-force control command.
     pub fn create_posforce_control_command(motor: &Motor, param: &PosForceParam) -> CANPacket {
         let limits = motor.motor_type().get_limits();
 
-        let q = clamp(param.q, -limits.p_max, limits.p_max);
-        let dq = clamp(param.dq, 0.0, limits.v_max);
+        let q = clamp(motor.position_to_raw(param.q), -limits.p_max, limits.p_max);
+        let dq = clamp(motor.velocity_to_raw(param.dq), 0.0, limits.v_max);
         let i = clamp(param.i, 0.0, 1.0);
 
         // Convert to fixed-point representation
@@ -186,6 +526,30 @@ impl CanPacketEncoder {
         }
     }
 
+    /// Create write parameter command (writes `value` to register `rid`).
+    pub fn create_write_param_command(
+        motor: &Motor,
+        rid: MotorVariable,
+        value: ParamValue,
+    ) -> CANPacket {
+        let can_id = motor.send_can_id();
+        let bytes = value.to_le_bytes();
+
+        CANPacket {
+            send_can_id: 0x7FF,
+            data: vec![
+                (can_id & 0xFF) as u8,
+                ((can_id >> 8) & 0xFF) as u8,
+                0x55,
+                rid as u8,
+                bytes[0],
+                bytes[1],
+                bytes[2],
+                bytes[3],
+            ],
+        }
+    }
+
     /// Create query parameter command.
     pub fn create_query_param_command(motor: &Motor, rid: MotorVariable) -> CANPacket {
         let can_id = motor.send_can_id();
@@ -204,6 +568,544 @@ impl CanPacketEncoder {
             ],
         }
     }
+
+    /// Create a save-parameters-to-flash command.
+    ///
+    /// Registers written with [`create_write_param_command`] live in RAM until
+    /// they are persisted; this command (protocol byte `0xAA`) commits the
+    /// current parameter set to the motor's flash so it survives a power cycle.
+    ///
+    /// [`create_write_param_command`]: Self::create_write_param_command
+    pub fn create_save_param_command(motor: &Motor) -> CANPacket {
+        let can_id = motor.send_can_id();
+
+        CANPacket {
+            send_can_id: 0x7FF,
+            data: vec![
+                (can_id & 0xFF) as u8,
+                ((can_id >> 8) & 0xFF) as u8,
+                0xAA,
+                0x01,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+            ],
+        }
+    }
+}
+
+/// Apply a motor's soft limits to an MIT command, returning a limited copy.
+///
+/// Position, velocity, and torque are clamped to the motor's configured ranges
+/// (or rejected in strict mode) before the command is mapped into the raw
+/// motor frame. The impedance gains `kp`/`kd` pass through unchanged.
+pub fn enforce_mit_limits(motor: &Motor, param: &MITParam) -> Result<MITParam> {
+    Ok(MITParam {
+        kp: param.kp,
+        kd: param.kd,
+        q: motor.enforce_position(param.q)?,
+        dq: motor.enforce_velocity(param.dq)?,
+        tau: motor.enforce_torque(param.tau)?,
+    })
+}
+
+/// Apply a motor's position and velocity soft limits to a PosVel command.
+pub fn enforce_posvel_limits(motor: &Motor, param: &PosVelParam) -> Result<PosVelParam> {
+    Ok(PosVelParam {
+        q: motor.enforce_position(param.q)?,
+        dq: motor.enforce_velocity(param.dq)?,
+    })
+}
+
+/// Apply a motor's velocity soft limit to a velocity command.
+pub fn enforce_vel_limits(motor: &Motor, param: &VelParam) -> Result<VelParam> {
+    Ok(VelParam {
+        dq: motor.enforce_velocity(param.dq)?,
+    })
+}
+
+/// Apply a motor's position, velocity, and torque soft limits to a PosForce
+/// command. The per-unit current channel `i` is treated as the force channel
+/// and clamped against the torque limit.
+pub fn enforce_posforce_limits(motor: &Motor, param: &PosForceParam) -> Result<PosForceParam> {
+    Ok(PosForceParam {
+        q: motor.enforce_position(param.q)?,
+        dq: motor.enforce_velocity(param.dq)?,
+        i: motor.enforce_torque(param.i)?,
+    })
+}
+
+/// Abstraction over the command encoding a device collection needs, so the
+/// registration/dispatch machinery can drive non-Damiao CAN servo families.
+///
+/// Each method returns a [`CANPacket`] carrying the send id and payload bytes.
+pub trait MotorController: Send + Sync {
+    /// Encode an enable command.
+    fn encode_enable(&self, motor: &Motor) -> CANPacket;
+    /// Encode a disable command.
+    fn encode_disable(&self, motor: &Motor) -> CANPacket;
+    /// Encode a set-zero command.
+    fn encode_set_zero(&self, motor: &Motor) -> CANPacket;
+    /// Encode a MIT control command.
+    fn encode_mit(&self, motor: &Motor, param: &MITParam) -> CANPacket;
+    /// Encode a position-velocity control command.
+    fn encode_posvel(&self, motor: &Motor, param: &PosVelParam) -> CANPacket;
+    /// Encode a velocity control command.
+    fn encode_vel(&self, motor: &Motor, param: &VelParam) -> CANPacket;
+    /// Encode a position-force control command.
+    fn encode_posforce(&self, motor: &Motor, param: &PosForceParam) -> CANPacket;
+    /// Encode a parameter query command.
+    fn encode_query_param(&self, motor: &Motor, rid: MotorVariable) -> CANPacket;
+}
+
+/// Damiao implementation of [`MotorController`], delegating to
+/// [`CanPacketEncoder`].
+#[derive(Debug, Clone, Default)]
+pub struct DamiaoController;
+
+impl MotorController for DamiaoController {
+    fn encode_enable(&self, motor: &Motor) -> CANPacket {
+        protocol_for(motor.motor_type()).encode_enable(motor)
+    }
+
+    fn encode_disable(&self, motor: &Motor) -> CANPacket {
+        protocol_for(motor.motor_type()).encode_disable(motor)
+    }
+
+    fn encode_set_zero(&self, motor: &Motor) -> CANPacket {
+        protocol_for(motor.motor_type()).encode_set_zero(motor)
+    }
+
+    fn encode_mit(&self, motor: &Motor, param: &MITParam) -> CANPacket {
+        protocol_for(motor.motor_type()).encode_mit(motor, param)
+    }
+
+    fn encode_posvel(&self, motor: &Motor, param: &PosVelParam) -> CANPacket {
+        protocol_for(motor.motor_type()).encode_posvel(motor, param)
+    }
+
+    fn encode_vel(&self, motor: &Motor, param: &VelParam) -> CANPacket {
+        protocol_for(motor.motor_type()).encode_vel(motor, param)
+    }
+
+    fn encode_posforce(&self, motor: &Motor, param: &PosForceParam) -> CANPacket {
+        protocol_for(motor.motor_type()).encode_posforce(motor, param)
+    }
+
+    fn encode_query_param(&self, motor: &Motor, rid: MotorVariable) -> CANPacket {
+        protocol_for(motor.motor_type()).encode_param_read(motor, rid)
+    }
+}
+
+/// Wire-protocol family a motor belongs to.
+///
+/// The Damiao types share the DM register map and 11-bit send/recv id pairs;
+/// CyberGear servos use 29-bit extended arbitration ids and a 16-bit indexed
+/// parameter space. [`protocol_for`] selects the matching [`MotorProtocol`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MotorFamily {
+    /// Damiao DM-series motors.
+    Damiao,
+    /// Xiaomi CyberGear motors.
+    CyberGear,
+}
+
+impl MotorType {
+    /// Wire-protocol family this motor model belongs to.
+    ///
+    /// Every model in the current [`MotorType`] set is a Damiao device; the tag
+    /// exists so a single bus can mix in CyberGear joints once they are added.
+    pub fn family(&self) -> MotorFamily {
+        MotorFamily::Damiao
+    }
+
+    /// Pack MIT parameters into a [`CANPacket`] using this model's limits.
+    ///
+    /// Mirrors the on-bus encoding of
+    /// [`create_mit_control_command`](CanPacketEncoder::create_mit_control_command)
+    /// but takes no live [`Motor`], so callers can craft and inspect frames
+    /// without a bus; the packet's `send_can_id` is left at 0.
+    pub fn pack_mit(&self, param: &MITParam) -> CANPacket {
+        let limits = self.get_limits();
+
+        let q = clamp(param.q, -limits.p_max, limits.p_max);
+        let dq = clamp(param.dq, -limits.v_max, limits.v_max);
+        let tau = clamp(param.tau, -limits.t_max, limits.t_max);
+        let kp = clamp(param.kp, 0.0, 500.0);
+        let kd = clamp(param.kd, 0.0, 5.0);
+
+        let q_int = float_to_uint(q, -limits.p_max, limits.p_max, 16);
+        let dq_int = float_to_uint(dq, -limits.v_max, limits.v_max, 12);
+        let kp_int = float_to_uint(kp, 0.0, 500.0, 12);
+        let kd_int = float_to_uint(kd, 0.0, 5.0, 12);
+        let tau_int = float_to_uint(tau, -limits.t_max, limits.t_max, 12);
+
+        let mut data = vec![0u8; 8];
+        data[0] = (q_int >> 8) as u8;
+        data[1] = (q_int & 0xFF) as u8;
+        data[2] = (dq_int >> 4) as u8;
+        data[3] = ((dq_int & 0x0F) << 4) as u8 | ((kp_int >> 8) & 0x0F) as u8;
+        data[4] = (kp_int & 0xFF) as u8;
+        data[5] = (kd_int >> 4) as u8;
+        data[6] = ((kd_int & 0x0F) << 4) as u8 | ((tau_int >> 8) & 0x0F) as u8;
+        data[7] = (tau_int & 0xFF) as u8;
+
+        CANPacket {
+            send_can_id: 0,
+            data,
+        }
+    }
+
+    /// Unpack a feedback payload into a [`MotorStateResult`] using this model's
+    /// limits, for debugging and unit-testing frame decoding off the bus.
+    pub fn unpack_state(&self, data: &[u8]) -> MotorStateResult {
+        if data.len() < 8 {
+            return MotorStateResult {
+                valid: false,
+                ..Default::default()
+            };
+        }
+
+        let limits = self.get_limits();
+
+        let q_raw = ((data[1] as u32) << 8) | (data[2] as u32);
+        let dq_raw = ((data[3] as u32) << 4) | ((data[4] >> 4) as u32);
+        let tau_raw = (((data[4] & 0x0F) as u32) << 8) | (data[5] as u32);
+
+        MotorStateResult {
+            position: uint_to_float(q_raw, -limits.p_max, limits.p_max, 16),
+            velocity: uint_to_float(dq_raw, -limits.v_max, limits.v_max, 12),
+            torque: uint_to_float(tau_raw, -limits.t_max, limits.t_max, 12),
+            t_mos: data[6] as i32,
+            t_rotor: data[7] as i32,
+            valid: true,
+        }
+    }
+}
+
+impl LimitParam {
+    /// Map a physical value in `[-max, max]` to an unsigned integer of `bits`,
+    /// using the standard MIT span map with rounding and clamping.
+    ///
+    /// `max` is the symmetric bound for the quantity (`p_max`, `v_max`, or
+    /// `t_max`); the lower bound is `-max`.
+    pub fn float_to_uint(value: f64, max: f64, bits: u32) -> u32 {
+        let min = -max;
+        let max_raw = ((1u64 << bits) - 1) as f64;
+        let raw = ((value - min) * (max_raw / (max - min))).round();
+        raw.clamp(0.0, max_raw) as u32
+    }
+
+    /// Inverse of [`float_to_uint`](Self::float_to_uint): map a raw integer back
+    /// to its physical value in `[-max, max]`.
+    pub fn uint_to_float(raw: u32, max: f64, bits: u32) -> f64 {
+        let min = -max;
+        let max_raw = ((1u64 << bits) - 1) as f64;
+        raw as f64 * (max - min) / max_raw + min
+    }
+}
+
+/// Abstraction over a motor wire protocol, so a collection can drive Damiao and
+/// CyberGear joints on the same bus through one code path.
+///
+/// Implementations own frame encoding/decoding and the arbitration-id layout.
+/// [`DamiaoProtocol`] wraps the existing [`CanPacketEncoder`]/[`CanPacketDecoder`];
+/// [`CyberGearProtocol`] implements the extended-id CyberGear format.
+pub trait MotorProtocol: Send + Sync {
+    /// Encode an enable command.
+    fn encode_enable(&self, motor: &Motor) -> CANPacket;
+    /// Encode a disable command.
+    fn encode_disable(&self, motor: &Motor) -> CANPacket;
+    /// Encode a set-zero command.
+    fn encode_set_zero(&self, motor: &Motor) -> CANPacket;
+    /// Encode a MIT control command.
+    fn encode_mit(&self, motor: &Motor, param: &MITParam) -> CANPacket;
+    /// Encode a position-velocity control command.
+    fn encode_posvel(&self, motor: &Motor, param: &PosVelParam) -> CANPacket;
+    /// Encode a velocity control command.
+    fn encode_vel(&self, motor: &Motor, param: &VelParam) -> CANPacket;
+    /// Encode a position-force control command.
+    fn encode_posforce(&self, motor: &Motor, param: &PosForceParam) -> CANPacket;
+    /// Encode a parameter-read request for register `rid`.
+    fn encode_param_read(&self, motor: &Motor, rid: MotorVariable) -> CANPacket;
+    /// Encode a parameter-write of `value` to register `rid`.
+    fn encode_param_write(&self, motor: &Motor, rid: MotorVariable, value: ParamValue)
+        -> CANPacket;
+    /// Decode a feedback frame into a motor state result.
+    fn decode_feedback(&self, motor: &Motor, data: &[u8]) -> MotorStateResult;
+    /// Decode a parameter-response frame.
+    fn decode_param(&self, data: &[u8]) -> ParamResult;
+}
+
+/// Damiao implementation of [`MotorProtocol`], delegating to the DM
+/// encoder/decoder.
+#[derive(Debug, Clone, Default)]
+pub struct DamiaoProtocol;
+
+impl MotorProtocol for DamiaoProtocol {
+    fn encode_enable(&self, motor: &Motor) -> CANPacket {
+        CanPacketEncoder::create_enable_command(motor)
+    }
+
+    fn encode_disable(&self, motor: &Motor) -> CANPacket {
+        CanPacketEncoder::create_disable_command(motor)
+    }
+
+    fn encode_set_zero(&self, motor: &Motor) -> CANPacket {
+        CanPacketEncoder::create_set_zero_command(motor)
+    }
+
+    fn encode_mit(&self, motor: &Motor, param: &MITParam) -> CANPacket {
+        CanPacketEncoder::create_mit_control_command(motor, param)
+    }
+
+    fn encode_posvel(&self, motor: &Motor, param: &PosVelParam) -> CANPacket {
+        CanPacketEncoder::create_posvel_control_command(motor, param)
+    }
+
+    fn encode_vel(&self, motor: &Motor, param: &VelParam) -> CANPacket {
+        CanPacketEncoder::create_vel_control_command(motor, param)
+    }
+
+    fn encode_posforce(&self, motor: &Motor, param: &PosForceParam) -> CANPacket {
+        CanPacketEncoder::create_posforce_control_command(motor, param)
+    }
+
+    fn encode_param_read(&self, motor: &Motor, rid: MotorVariable) -> CANPacket {
+        CanPacketEncoder::create_query_param_command(motor, rid)
+    }
+
+    fn encode_param_write(
+        &self,
+        motor: &Motor,
+        rid: MotorVariable,
+        value: ParamValue,
+    ) -> CANPacket {
+        CanPacketEncoder::create_write_param_command(motor, rid, value)
+    }
+
+    fn decode_feedback(&self, motor: &Motor, data: &[u8]) -> MotorStateResult {
+        CanPacketDecoder::parse_motor_state_data(motor, data)
+    }
+
+    fn decode_param(&self, data: &[u8]) -> ParamResult {
+        CanPacketDecoder::parse_motor_param_data(data)
+    }
+}
+
+/// CyberGear communication-type codes (high 5 bits of the arbitration id).
+mod cybergear_cmd {
+    /// Operation (motion) control.
+    pub const MOTION: u32 = 1;
+    /// Enable the motor.
+    pub const ENABLE: u32 = 3;
+    /// Stop/disable the motor.
+    pub const STOP: u32 = 4;
+    /// Set the mechanical zero position.
+    pub const SET_ZERO: u32 = 6;
+    /// Read a single parameter.
+    pub const PARAM_READ: u32 = 17;
+    /// Write a single parameter.
+    pub const PARAM_WRITE: u32 = 18;
+}
+
+/// CyberGear 16-bit parameter indices.
+mod cybergear_index {
+    /// Run mode selector (byte).
+    pub const RUN_MODE: u16 = 0x7005;
+    /// Current reference (float32).
+    pub const IQ_REF: u16 = 0x7006;
+    /// Speed reference (float32).
+    pub const SPD_REF: u16 = 0x700A;
+    /// Torque limit (float32).
+    pub const LIMIT_TORQUE: u16 = 0x700B;
+    /// Position reference (float32).
+    pub const LOC_REF: u16 = 0x7016;
+    /// Mechanical position feedback (float32).
+    pub const MECH_POS: u16 = 0x7019;
+    /// Mechanical velocity feedback (float32).
+    pub const MECH_VEL: u16 = 0x701B;
+    /// Bus voltage feedback (float32).
+    pub const VBUS: u16 = 0x701C;
+    /// Rotation turn count (int16).
+    pub const ROTATION: u16 = 0x701D;
+}
+
+/// Default host id used in the CyberGear arbitration layout.
+const CYBERGEAR_HOST_ID: u32 = 0;
+
+/// Xiaomi CyberGear implementation of [`MotorProtocol`].
+///
+/// CyberGear arbitration ids are 29-bit extended ids composed as
+/// `(cmd & 0x1f) << 24 | (host_id << 8) | motor_id`, where `motor_id` is the
+/// low byte of the motor's send id. Parameters are addressed by 16-bit index
+/// with an explicit type tag rather than the DM single-byte register map.
+#[derive(Debug, Clone, Default)]
+pub struct CyberGearProtocol;
+
+impl CyberGearProtocol {
+    /// Compose the 29-bit extended arbitration id for a command type.
+    fn arbitration_id(motor: &Motor, cmd: u32) -> u32 {
+        let motor_id = motor.send_can_id() & 0xFF;
+        ((cmd & 0x1F) << 24) | (CYBERGEAR_HOST_ID << 8) | motor_id
+    }
+
+    /// Build a type-18 parameter-write frame for a float32 register.
+    fn write_f32(motor: &Motor, index: u16, value: f32) -> CANPacket {
+        let mut data = vec![0u8; 8];
+        data[0..2].copy_from_slice(&index.to_le_bytes());
+        data[4..8].copy_from_slice(&value.to_le_bytes());
+        CANPacket {
+            send_can_id: Self::arbitration_id(motor, cybergear_cmd::PARAM_WRITE),
+            data,
+        }
+    }
+
+    /// Map a DM register onto the nearest CyberGear parameter index, if any.
+    fn index_for(rid: MotorVariable) -> Option<u16> {
+        match rid {
+            MotorVariable::CTRL_MODE => Some(cybergear_index::RUN_MODE),
+            _ => None,
+        }
+    }
+}
+
+impl MotorProtocol for CyberGearProtocol {
+    fn encode_enable(&self, motor: &Motor) -> CANPacket {
+        CANPacket {
+            send_can_id: Self::arbitration_id(motor, cybergear_cmd::ENABLE),
+            data: vec![0u8; 8],
+        }
+    }
+
+    fn encode_disable(&self, motor: &Motor) -> CANPacket {
+        CANPacket {
+            send_can_id: Self::arbitration_id(motor, cybergear_cmd::STOP),
+            data: vec![0u8; 8],
+        }
+    }
+
+    fn encode_set_zero(&self, motor: &Motor) -> CANPacket {
+        let mut data = vec![0u8; 8];
+        data[0] = 1;
+        CANPacket {
+            send_can_id: Self::arbitration_id(motor, cybergear_cmd::SET_ZERO),
+            data,
+        }
+    }
+
+    fn encode_mit(&self, motor: &Motor, param: &MITParam) -> CANPacket {
+        let limits = motor.motor_type().get_limits();
+        let q = clamp(motor.position_to_raw(param.q), -limits.p_max, limits.p_max);
+        let dq = clamp(motor.velocity_to_raw(param.dq), -limits.v_max, limits.v_max);
+        let tau = clamp(motor.torque_to_raw(param.tau), -limits.t_max, limits.t_max);
+        let kp = clamp(param.kp, 0.0, 500.0);
+        let kd = clamp(param.kd, 0.0, 5.0);
+
+        // Motion frames carry target angle/velocity/kp/kd as 16-bit spans and
+        // the torque setpoint in bits 8..23 of the arbitration id, between
+        // the command type and the motor id.
+        let q_int = float_to_uint(q, -limits.p_max, limits.p_max, 16);
+        let dq_int = float_to_uint(dq, -limits.v_max, limits.v_max, 16);
+        let kp_int = float_to_uint(kp, 0.0, 500.0, 16);
+        let kd_int = float_to_uint(kd, 0.0, 5.0, 16);
+        let tau_int = float_to_uint(tau, -limits.t_max, limits.t_max, 16);
+
+        let motor_id = motor.send_can_id() & 0xFF;
+        let send_can_id =
+            ((cybergear_cmd::MOTION & 0x1F) << 24) | ((tau_int & 0xFFFF) << 8) | motor_id;
+
+        let mut data = vec![0u8; 8];
+        data[0..2].copy_from_slice(&(q_int as u16).to_be_bytes());
+        data[2..4].copy_from_slice(&(dq_int as u16).to_be_bytes());
+        data[4..6].copy_from_slice(&(kp_int as u16).to_be_bytes());
+        data[6..8].copy_from_slice(&(kd_int as u16).to_be_bytes());
+
+        CANPacket { send_can_id, data }
+    }
+
+    fn encode_posvel(&self, motor: &Motor, param: &PosVelParam) -> CANPacket {
+        // Position mode on CyberGear is a loc_ref write; the speed reference is
+        // configured separately and left at its current value here.
+        Self::write_f32(motor, cybergear_index::LOC_REF, motor.position_to_raw(param.q) as f32)
+    }
+
+    fn encode_vel(&self, motor: &Motor, param: &VelParam) -> CANPacket {
+        Self::write_f32(motor, cybergear_index::SPD_REF, motor.velocity_to_raw(param.dq) as f32)
+    }
+
+    fn encode_posforce(&self, motor: &Motor, param: &PosForceParam) -> CANPacket {
+        // Approximate by commanding the position reference only; the current
+        // setpoint `i` is not sent, and the torque-limit register is not written.
+        Self::write_f32(motor, cybergear_index::LOC_REF, motor.position_to_raw(param.q) as f32)
+    }
+
+    fn encode_param_read(&self, motor: &Motor, rid: MotorVariable) -> CANPacket {
+        let index = Self::index_for(rid).unwrap_or(rid as u16);
+        let mut data = vec![0u8; 8];
+        data[0..2].copy_from_slice(&index.to_le_bytes());
+        CANPacket {
+            send_can_id: Self::arbitration_id(motor, cybergear_cmd::PARAM_READ),
+            data,
+        }
+    }
+
+    fn encode_param_write(
+        &self,
+        motor: &Motor,
+        rid: MotorVariable,
+        value: ParamValue,
+    ) -> CANPacket {
+        let index = Self::index_for(rid).unwrap_or(rid as u16);
+        Self::write_f32(motor, index, value.as_f64() as f32)
+    }
+
+    fn decode_feedback(&self, _motor: &Motor, data: &[u8]) -> MotorStateResult {
+        if data.len() < 8 {
+            return MotorStateResult {
+                valid: false,
+                ..Default::default()
+            };
+        }
+        // Feedback frames carry angle/velocity/torque as big-endian 16-bit
+        // spans over the fixed CyberGear ranges (±4π rad, ±30 rad/s, ±12 N·m).
+        let q_raw = ((data[0] as u32) << 8) | data[1] as u32;
+        let dq_raw = ((data[2] as u32) << 8) | data[3] as u32;
+        let tau_raw = ((data[4] as u32) << 8) | data[5] as u32;
+        MotorStateResult {
+            position: uint_to_float(q_raw, -4.0 * std::f64::consts::PI, 4.0 * std::f64::consts::PI, 16),
+            velocity: uint_to_float(dq_raw, -30.0, 30.0, 16),
+            torque: uint_to_float(tau_raw, -12.0, 12.0, 16),
+            t_mos: 0,
+            t_rotor: ((data[6] as i32) << 8 | data[7] as i32) / 10,
+            valid: true,
+        }
+    }
+
+    fn decode_param(&self, data: &[u8]) -> ParamResult {
+        if data.len() < 8 {
+            return ParamResult {
+                valid: false,
+                ..Default::default()
+            };
+        }
+        let index = u16::from_le_bytes([data[0], data[1]]);
+        let value = f32::from_le_bytes([data[4], data[5], data[6], data[7]]) as f64;
+        ParamResult {
+            rid: index as i32,
+            value,
+            valid: true,
+        }
+    }
+}
+
+/// Select the [`MotorProtocol`] for a motor model's family.
+pub fn protocol_for(motor_type: MotorType) -> Box<dyn MotorProtocol> {
+    match motor_type.family() {
+        MotorFamily::Damiao => Box::new(DamiaoProtocol),
+        MotorFamily::CyberGear => Box::new(CyberGearProtocol),
+    }
 }
 
 /// CAN packet decoder for Damiao motor responses.
@@ -249,6 +1151,17 @@ impl CanPacketDecoder {
         }
     }
 
+    /// Decode the amplifier fault/run state from a feedback frame.
+    ///
+    /// The status nibble lives in the high nibble of the first data byte,
+    /// alongside the low bits of the CAN id.
+    pub fn parse_motor_fault_status(data: &[u8]) -> MotorFaultStatus {
+        if data.is_empty() {
+            return MotorFaultStatus::default();
+        }
+        MotorFaultStatus::from_code(data[0] >> 4)
+    }
+
     /// Parse parameter data from CAN frame.
     pub fn parse_motor_param_data(data: &[u8]) -> ParamResult {
         if data.len() < 8 {
@@ -290,6 +1203,7 @@ impl CanPacketDecoder {
         let limits = motor.motor_type().get_limits();
 
         // Extract raw values from packed data
+        let error_state = data[0] >> 4;
         let q_raw = ((data[1] as u32) << 8) | (data[2] as u32);
         let dq_raw = ((data[3] as u32) << 4) | ((data[4] >> 4) as u32);
         let tau_raw = (((data[4] & 0x0F) as u32) << 8) | (data[5] as u32);
@@ -301,7 +1215,7 @@ impl CanPacketDecoder {
         let velocity = uint_to_float(dq_raw, -limits.v_max, limits.v_max, 12);
         let torque = uint_to_float(tau_raw, -limits.t_max, limits.t_max, 12);
 
-        motor.update_state(position, velocity, torque, t_mos, t_rotor);
+        motor.update_state(position, velocity, torque, t_mos, t_rotor, error_state);
         true
     }
 
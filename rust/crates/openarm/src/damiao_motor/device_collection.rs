@@ -1,29 +1,376 @@
 //! Device collection for managing multiple motors.
 
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use crate::canbus::{CANDeviceCollection, MotorDeviceCan};
 use crate::error::{OpenArmError, Result};
 
 use super::constants::*;
-use super::control::CanPacketEncoder;
+use super::control::{
+    enforce_mit_limits, enforce_posforce_limits, enforce_posvel_limits, enforce_vel_limits,
+    CanPacketEncoder, DamiaoController, MotorController, MotorFaultStatus, ParamValue,
+    ParamWireType, VelParam,
+};
 use super::motor::Motor;
 
+/// Status nibble values at or above this threshold are Damiao fault codes
+/// (over-voltage, over-current, over-temperature, overload, ...).
+const DM_FAULT_STATE_MIN: u8 = 8;
+
+/// Health of a single motor as reported by `check_liveness`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MotorHealth {
+    /// No state frame has been ingested within the liveness timeout.
+    Stale,
+    /// The motor is reporting a Damiao fault code in its last state frame.
+    Fault { error_state: u8 },
+}
+
+/// Policy applied automatically when a motor stops responding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FaultPolicy {
+    /// Report stale/faulted motors but take no action.
+    #[default]
+    Report,
+    /// Disable a motor as soon as it is detected stale or faulted.
+    DisableOnLoss,
+}
+
+/// Coherent snapshot of a single motor's latest decoded feedback.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotorTelemetry {
+    pub position: f64,
+    pub velocity: f64,
+    pub torque: f64,
+    pub t_mos: i32,
+    pub t_rotor: i32,
+    pub error_state: u8,
+    pub enabled: bool,
+}
+
+impl MotorTelemetry {
+    /// Build a snapshot from a motor's cached state.
+    fn from_motor(motor: &Motor) -> Self {
+        Self {
+            position: motor.get_position(),
+            velocity: motor.get_velocity(),
+            torque: motor.get_torque(),
+            t_mos: motor.get_state_tmos(),
+            t_rotor: motor.get_state_trotor(),
+            error_state: motor.get_error_state(),
+            enabled: motor.is_enabled(),
+        }
+    }
+}
+
+/// Command watchdog state: trips when no control command is fed within the
+/// configured window.
+#[derive(Debug, Clone, Copy, Default)]
+struct Watchdog {
+    timeout: Option<Duration>,
+    last_command: Option<Instant>,
+}
+
+/// Reason a motor tripped the safety watchdog.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SafetyTrip {
+    /// MOS temperature (°C) exceeded the configured limit.
+    MosTemp(i32),
+    /// Rotor temperature (°C) exceeded the configured limit.
+    RotorTemp(i32),
+    /// Per-unit torque magnitude exceeded the configured limit.
+    Torque(f64),
+}
+
+/// Thermal/current safety thresholds checked after each `recv_all`.
+#[derive(Debug, Clone, Copy)]
+struct SafetyLimits {
+    enabled: bool,
+    max_mos_temp_c: i32,
+    max_rotor_temp_c: i32,
+    max_torque_pu: f64,
+}
+
+impl Default for SafetyLimits {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_mos_temp_c: i32::MAX,
+            max_rotor_temp_c: i32::MAX,
+            max_torque_pu: f64::INFINITY,
+        }
+    }
+}
+
+/// Per-joint target for [`DMDeviceCollection::run_trajectory`].
+#[derive(Debug, Clone, Copy)]
+pub struct Waypoint {
+    /// Absolute target motor position in radians.
+    pub position: f64,
+    /// Max velocity in rad/s; defaults to half the motor's `v_max`.
+    pub max_velocity: Option<f64>,
+    /// Max acceleration in rad/s²; defaults to reaching `max_velocity` in 0.25 s.
+    pub max_acceleration: Option<f64>,
+}
+
+/// A trapezoidal velocity profile stretched to a fixed total duration so that
+/// every joint reaches its target at the same instant.
+#[derive(Debug, Clone, Copy)]
+struct TrapezoidProfile {
+    start: f64,
+    sign: f64,
+    distance: f64,
+    accel: f64,
+    cruise_vel: f64,
+    accel_time: f64,
+    total_time: f64,
+}
+
+impl TrapezoidProfile {
+    /// Interpolated position at elapsed time `t` (seconds), clamped to the
+    /// motion endpoints outside `[0, total_time]`.
+    fn position_at(&self, t: f64) -> f64 {
+        let traveled = if self.distance == 0.0 || t <= 0.0 {
+            0.0
+        } else if t < self.accel_time {
+            0.5 * self.accel * t * t
+        } else if t < self.total_time - self.accel_time {
+            0.5 * self.accel * self.accel_time * self.accel_time
+                + self.cruise_vel * (t - self.accel_time)
+        } else if t < self.total_time {
+            let remaining = self.total_time - t;
+            self.distance - 0.5 * self.accel * remaining * remaining
+        } else {
+            self.distance
+        };
+        self.start + self.sign * traveled
+    }
+}
+
+/// Minimum time to move `distance` under a symmetric trapezoid bounded by
+/// `v_max`/`a_max`. Triangular when the move is too short to reach `v_max`.
+fn min_trapezoid_time(distance: f64, v_max: f64, a_max: f64) -> f64 {
+    if distance <= 0.0 {
+        return 0.0;
+    }
+    if distance < v_max * v_max / a_max {
+        2.0 * (distance / a_max).sqrt()
+    } else {
+        v_max / a_max + distance / v_max
+    }
+}
+
+/// Build a trapezoid covering `distance` under `accel` that lasts exactly
+/// `total_time`, lowering the cruise velocity as needed to stretch the move.
+fn stretch_trapezoid(start: f64, sign: f64, distance: f64, accel: f64, total_time: f64) -> TrapezoidProfile {
+    if distance == 0.0 || total_time <= 0.0 {
+        return TrapezoidProfile {
+            start,
+            sign,
+            distance,
+            accel,
+            cruise_vel: 0.0,
+            accel_time: 0.0,
+            total_time,
+        };
+    }
+    // total_time = v/accel + distance/v  =>  v² - (total_time·accel)·v + accel·distance = 0
+    let b = total_time * accel;
+    let disc = (b * b - 4.0 * accel * distance).max(0.0);
+    let cruise_vel = 0.5 * (b - disc.sqrt());
+    TrapezoidProfile {
+        start,
+        sign,
+        distance,
+        accel,
+        cruise_vel,
+        accel_time: cruise_vel / accel,
+        total_time,
+    }
+}
+
+/// Drive `recv_all` until the response for `rid` is stored on `motor` or the
+/// timeout elapses, then decode it according to the register's wire type.
+fn read_param_poll(
+    motor: &Motor,
+    rid: MotorVariable,
+    timeout: Duration,
+    mut recv_all: impl FnMut(u64) -> Result<usize>,
+) -> Result<ParamValue> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        recv_all(1000)?;
+        if let Some(raw) = motor.get_temp_param(rid as i32) {
+            let value = match rid.wire_type() {
+                ParamWireType::F32 => ParamValue::F32(raw as f32),
+                ParamWireType::U32 => match rid {
+                    MotorVariable::MST_ID | MotorVariable::ESC_ID => ParamValue::U32(raw as u32),
+                    // The other integer registers are stored as the payload
+                    // reinterpreted as f32; recover the original bits.
+                    _ => ParamValue::U32((raw as f32).to_bits()),
+                },
+                ParamWireType::I32 => ParamValue::I32(raw as i32),
+            };
+            return Ok(value);
+        }
+        if Instant::now() >= deadline {
+            return Err(OpenArmError::ResponseTimeout(rid as i32));
+        }
+    }
+}
+
 /// Collection of Damiao motor devices.
 pub struct DMDeviceCollection {
     motors: Vec<Motor>,
     devices: Vec<Arc<Mutex<MotorDeviceCan>>>,
     pub(crate) collection: Arc<CANDeviceCollection>,
+    fault_policy: Mutex<FaultPolicy>,
+    watchdog: Mutex<Watchdog>,
+    safety: Mutex<SafetyLimits>,
+    controller: Box<dyn MotorController>,
 }
 
 impl DMDeviceCollection {
-    /// Create from shared collection.
+    /// Create from shared collection, using the Damiao command encoding.
     pub fn from_collection(collection: Arc<CANDeviceCollection>) -> Self {
+        Self::with_controller(collection, Box::new(DamiaoController))
+    }
+
+    /// Create from shared collection with a custom motor controller, allowing
+    /// mixed or non-Damiao servo families on a shared `CANDeviceCollection`.
+    pub fn with_controller(
+        collection: Arc<CANDeviceCollection>,
+        controller: Box<dyn MotorController>,
+    ) -> Self {
         Self {
             motors: Vec::new(),
             devices: Vec::new(),
             collection,
+            fault_policy: Mutex::new(FaultPolicy::Report),
+            watchdog: Mutex::new(Watchdog::default()),
+            safety: Mutex::new(SafetyLimits::default()),
+            controller,
+        }
+    }
+
+    /// Configure the thermal/current safety thresholds. Torque is compared as
+    /// a per-unit fraction of each motor type's torque limit.
+    pub fn set_safety_limits(
+        &self,
+        max_mos_temp_c: i32,
+        max_rotor_temp_c: i32,
+        max_torque_pu: f64,
+    ) {
+        let mut safety = self.safety.lock().unwrap();
+        safety.max_mos_temp_c = max_mos_temp_c;
+        safety.max_rotor_temp_c = max_rotor_temp_c;
+        safety.max_torque_pu = max_torque_pu;
+    }
+
+    /// Enable or disable the safety watchdog. When enabled, `recv_all` checks
+    /// every motor against the configured limits and disables offenders.
+    pub fn enable_safety_watchdog(&self, enable: bool) {
+        self.safety.lock().unwrap().enabled = enable;
+    }
+
+    /// Check every motor's latest decoded state against the safety limits,
+    /// disabling any motor that exceeds a threshold. Returns the motors that
+    /// tripped and why.
+    pub fn poll_safety(&self) -> Result<Vec<(usize, SafetyTrip)>> {
+        let limits = *self.safety.lock().unwrap();
+        let mut tripped = Vec::new();
+        for (index, motor) in self.motors.iter().enumerate() {
+            let torque_pu = (motor.get_raw_torque() / motor.motor_type().get_limits().t_max).abs();
+            let trip = if motor.get_state_tmos() > limits.max_mos_temp_c {
+                Some(SafetyTrip::MosTemp(motor.get_state_tmos()))
+            } else if motor.get_state_trotor() > limits.max_rotor_temp_c {
+                Some(SafetyTrip::RotorTemp(motor.get_state_trotor()))
+            } else if torque_pu > limits.max_torque_pu {
+                Some(SafetyTrip::Torque(torque_pu))
+            } else {
+                None
+            };
+            if let Some(trip) = trip {
+                let packet = self.controller.encode_disable(motor);
+                self.collection.send_packet(packet.send_can_id, &packet.data)?;
+                tripped.push((index, trip));
+            }
+        }
+        Ok(tripped)
+    }
+
+    /// Set the policy applied to motors that go stale or fault.
+    pub fn set_fault_policy(&self, policy: FaultPolicy) {
+        *self.fault_policy.lock().unwrap() = policy;
+    }
+
+    /// Arm a command watchdog that idles all motors if no control command is
+    /// issued within `timeout`. The window is reset immediately.
+    pub fn set_watchdog(&self, timeout: Duration) {
+        let mut watchdog = self.watchdog.lock().unwrap();
+        watchdog.timeout = Some(timeout);
+        watchdog.last_command = Some(Instant::now());
+    }
+
+    /// Reset the watchdog window. Call this from a control loop that has no
+    /// command to send but is still alive. A no-op if no watchdog is armed.
+    pub fn feed_watchdog(&self) {
+        let mut watchdog = self.watchdog.lock().unwrap();
+        if watchdog.timeout.is_some() {
+            watchdog.last_command = Some(Instant::now());
+        }
+    }
+
+    /// Check the watchdog and, if the window has elapsed, disable all motors.
+    /// Returns `Ok(true)` when the watchdog tripped this call.
+    pub fn poll_watchdog(&self) -> Result<bool> {
+        let tripped = {
+            let watchdog = self.watchdog.lock().unwrap();
+            match (watchdog.timeout, watchdog.last_command) {
+                (Some(timeout), Some(last)) => last.elapsed() > timeout,
+                _ => false,
+            }
+        };
+        if tripped {
+            self.disable_all()?;
+            self.watchdog.lock().unwrap().last_command = None;
+        }
+        Ok(tripped)
+    }
+
+    /// Report motors that have gone stale (no state frame within `timeout`) or
+    /// are reporting a fault code, stamped from the cached Damiao feedback that
+    /// `recv_all` ingests. When the fault policy is `DisableOnLoss`, each
+    /// reported motor is disabled through the normal disable path.
+    pub fn check_liveness(&self, timeout: Duration) -> Vec<(usize, MotorHealth)> {
+        let now = Instant::now();
+        let mut report = Vec::new();
+        for (index, motor) in self.motors.iter().enumerate() {
+            let health = match motor.last_seen() {
+                Some(seen) if now.duration_since(seen) <= timeout => {
+                    let error_state = motor.get_error_state();
+                    if error_state >= DM_FAULT_STATE_MIN {
+                        Some(MotorHealth::Fault { error_state })
+                    } else {
+                        None
+                    }
+                }
+                _ => Some(MotorHealth::Stale),
+            };
+            if let Some(health) = health {
+                report.push((index, health));
+            }
         }
+
+        if *self.fault_policy.lock().unwrap() == FaultPolicy::DisableOnLoss {
+            for &(index, _) in &report {
+                let packet = self.controller.encode_disable(&self.motors[index]);
+                let _ = self.collection.send_packet(packet.send_can_id, &packet.data);
+            }
+        }
+
+        report
     }
 
     /// Add a motor and its device.
@@ -59,7 +406,7 @@ impl DMDeviceCollection {
     /// Enable all motors.
     pub fn enable_all(&self) -> Result<()> {
         for motor in &self.motors {
-            let packet = CanPacketEncoder::create_enable_command(motor);
+            let packet = self.controller.encode_enable(motor);
             self.collection.send_packet(packet.send_can_id, &packet.data)?;
         }
         Ok(())
@@ -68,7 +415,7 @@ impl DMDeviceCollection {
     /// Disable all motors.
     pub fn disable_all(&self) -> Result<()> {
         for motor in &self.motors {
-            let packet = CanPacketEncoder::create_disable_command(motor);
+            let packet = self.controller.encode_disable(motor);
             self.collection.send_packet(packet.send_can_id, &packet.data)?;
         }
         Ok(())
@@ -77,7 +424,7 @@ impl DMDeviceCollection {
     /// Set zero position for all motors.
     pub fn set_zero_all(&self) -> Result<()> {
         for motor in &self.motors {
-            let packet = CanPacketEncoder::create_set_zero_command(motor);
+            let packet = self.controller.encode_set_zero(motor);
             self.collection.send_packet(packet.send_can_id, &packet.data)?;
         }
         Ok(())
@@ -103,10 +450,39 @@ impl DMDeviceCollection {
         Ok(())
     }
 
+    /// Clear the amplifier fault latch for all motors.
+    pub fn clear_faults_all(&self) -> Result<()> {
+        for motor in &self.motors {
+            let packet = CanPacketEncoder::create_clear_error_command(motor);
+            self.collection.send_packet(packet.send_can_id, &packet.data)?;
+        }
+        Ok(())
+    }
+
+    /// Clear the amplifier fault latch for one motor.
+    pub fn clear_faults_one(&self, index: usize) -> Result<()> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        let packet = CanPacketEncoder::create_clear_error_command(motor);
+        self.collection.send_packet(packet.send_can_id, &packet.data)?;
+        Ok(())
+    }
+
+    /// Decode the structured fault status for one motor.
+    pub fn fault_status(&self, index: usize) -> Result<MotorFaultStatus> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        Ok(motor.fault_status())
+    }
+
     /// Query parameter for all motors.
     pub fn query_param_all(&self, rid: MotorVariable) -> Result<()> {
         for motor in &self.motors {
-            let packet = CanPacketEncoder::create_query_param_command(motor, rid);
+            let packet = self.controller.encode_query_param(motor, rid);
             self.collection.send_packet(packet.send_can_id, &packet.data)?;
         }
         Ok(())
@@ -118,85 +494,352 @@ impl DMDeviceCollection {
             .motors
             .get(index)
             .ok_or(OpenArmError::IndexOutOfRange(index))?;
-        let packet = CanPacketEncoder::create_query_param_command(motor, rid);
+        let packet = self.controller.encode_query_param(motor, rid);
         self.collection.send_packet(packet.send_can_id, &packet.data)?;
         Ok(())
     }
 
+    /// Write a typed parameter to one motor's register.
+    pub fn write_param_one(
+        &self,
+        index: usize,
+        rid: MotorVariable,
+        value: ParamValue,
+    ) -> Result<()> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        let packet = CanPacketEncoder::create_write_param_command(motor, rid, value);
+        self.collection.send_packet(packet.send_can_id, &packet.data)
+    }
+
+    /// Write a typed parameter to every motor's register.
+    pub fn write_param_all(&self, rid: MotorVariable, value: ParamValue) -> Result<()> {
+        for motor in &self.motors {
+            let packet = CanPacketEncoder::create_write_param_command(motor, rid, value);
+            self.collection.send_packet(packet.send_can_id, &packet.data)?;
+        }
+        Ok(())
+    }
+
+    /// Read a parameter synchronously: send the query, drive `recv_all`, and
+    /// return the decoded value once the response for this motor's receive CAN
+    /// id and register id arrives, or time out.
+    pub fn read_param_one(
+        &self,
+        index: usize,
+        rid: MotorVariable,
+        timeout: Duration,
+    ) -> Result<ParamValue> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        let device = self
+            .devices
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+
+        let prev_mode = device.lock().unwrap().get_callback_mode();
+        device.lock().unwrap().set_callback_mode(CallbackMode::PARAM);
+        motor.clear_temp_param(rid as i32);
+
+        let query = self.controller.encode_query_param(motor, rid);
+        let result = self
+            .collection
+            .send_packet(query.send_can_id, &query.data)
+            .and_then(|()| read_param_poll(motor, rid, timeout, |us| self.collection.recv_all(us)));
+
+        device.lock().unwrap().set_callback_mode(prev_mode);
+        result
+    }
+
+    /// Write a register by value, encoding `value` in the register's wire type
+    /// and returning the acknowledged [`ParamResult`].
+    ///
+    /// The value is encoded as u32/i32/f32 according to [`MotorVariable::wire_type`];
+    /// read-only registers are rejected with [`OpenArmError::ReadOnlyRegister`].
+    pub fn set_param_one(
+        &self,
+        index: usize,
+        rid: MotorVariable,
+        value: f64,
+        timeout: Duration,
+    ) -> Result<ParamResult> {
+        if !rid.is_writable() {
+            return Err(OpenArmError::ReadOnlyRegister(rid as i32));
+        }
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        let device = self
+            .devices
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+
+        let encoded = ParamValue::from_f64(rid.wire_type(), value);
+
+        let prev_mode = device.lock().unwrap().get_callback_mode();
+        device.lock().unwrap().set_callback_mode(CallbackMode::PARAM);
+        motor.clear_temp_param(rid as i32);
+
+        let write = CanPacketEncoder::create_write_param_command(motor, rid, encoded);
+        let result = self
+            .collection
+            .send_packet(write.send_can_id, &write.data)
+            .and_then(|()| read_param_poll(motor, rid, timeout, |us| self.collection.recv_all(us)))
+            .map(|echoed| ParamResult {
+                rid: rid as i32,
+                value: echoed.as_f64(),
+                valid: true,
+            });
+
+        device.lock().unwrap().set_callback_mode(prev_mode);
+        result
+    }
+
+    /// Write a register by value to every motor (fire-and-forget).
+    pub fn set_param_all(&self, rid: MotorVariable, value: f64) -> Result<()> {
+        if !rid.is_writable() {
+            return Err(OpenArmError::ReadOnlyRegister(rid as i32));
+        }
+        let encoded = ParamValue::from_f64(rid.wire_type(), value);
+        for motor in &self.motors {
+            let packet = CanPacketEncoder::create_write_param_command(motor, rid, encoded);
+            self.collection.send_packet(packet.send_can_id, &packet.data)?;
+        }
+        Ok(())
+    }
+
+    /// Snapshot the latest decoded feedback for one motor.
+    pub fn telemetry_one(&self, index: usize) -> Result<MotorTelemetry> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        Ok(MotorTelemetry::from_motor(motor))
+    }
+
+    /// Snapshot the latest decoded feedback for every motor.
+    pub fn telemetry_all(&self) -> Vec<MotorTelemetry> {
+        self.motors.iter().map(MotorTelemetry::from_motor).collect()
+    }
+
     /// MIT control for one motor.
     pub fn mit_control_one(&self, index: usize, param: &MITParam) -> Result<()> {
+        self.feed_watchdog();
         let motor = self
             .motors
             .get(index)
             .ok_or(OpenArmError::IndexOutOfRange(index))?;
-        let packet = CanPacketEncoder::create_mit_control_command(motor, param);
+        let param = enforce_mit_limits(motor, param)?;
+        let packet = self.controller.encode_mit(motor, &param);
         self.collection.send_packet(packet.send_can_id, &packet.data)?;
         Ok(())
     }
 
     /// MIT control for all motors.
     pub fn mit_control_all(&self, params: &[MITParam]) -> Result<()> {
+        self.feed_watchdog();
         if params.len() != self.motors.len() {
             return Err(OpenArmError::ParamCountMismatch {
                 expected: self.motors.len(),
                 actual: params.len(),
             });
         }
-        for (motor, param) in self.motors.iter().zip(params.iter()) {
-            let packet = CanPacketEncoder::create_mit_control_command(motor, param);
-            self.collection.send_packet(packet.send_can_id, &packet.data)?;
-        }
-        Ok(())
+        let frames: Vec<(u32, [u8; 8])> = self
+            .motors
+            .iter()
+            .zip(params.iter())
+            .map(|(motor, param)| {
+                let param = enforce_mit_limits(motor, param)?;
+                let packet = self.controller.encode_mit(motor, &param);
+                Ok((packet.send_can_id, packet.data.try_into().unwrap()))
+            })
+            .collect::<Result<_>>()?;
+        self.collection.send_batch(&frames, true)
     }
 
     /// Position-velocity control for one motor.
     pub fn posvel_control_one(&self, index: usize, param: &PosVelParam) -> Result<()> {
+        self.feed_watchdog();
         let motor = self
             .motors
             .get(index)
             .ok_or(OpenArmError::IndexOutOfRange(index))?;
-        let packet = CanPacketEncoder::create_posvel_control_command(motor, param);
+        let param = enforce_posvel_limits(motor, param)?;
+        let packet = self.controller.encode_posvel(motor, &param);
         self.collection.send_packet(packet.send_can_id, &packet.data)?;
         Ok(())
     }
 
     /// Position-velocity control for all motors.
     pub fn posvel_control_all(&self, params: &[PosVelParam]) -> Result<()> {
+        self.feed_watchdog();
         if params.len() != self.motors.len() {
             return Err(OpenArmError::ParamCountMismatch {
                 expected: self.motors.len(),
                 actual: params.len(),
             });
         }
-        for (motor, param) in self.motors.iter().zip(params.iter()) {
-            let packet = CanPacketEncoder::create_posvel_control_command(motor, param);
-            self.collection.send_packet(packet.send_can_id, &packet.data)?;
-        }
+        let frames: Vec<(u32, [u8; 8])> = self
+            .motors
+            .iter()
+            .zip(params.iter())
+            .map(|(motor, param)| {
+                let param = enforce_posvel_limits(motor, param)?;
+                let packet = self.controller.encode_posvel(motor, &param);
+                Ok((packet.send_can_id, packet.data.try_into().unwrap()))
+            })
+            .collect::<Result<_>>()?;
+        self.collection.send_batch(&frames, true)
+    }
+
+    /// Velocity control for one motor.
+    pub fn vel_control_one(&self, index: usize, param: &VelParam) -> Result<()> {
+        self.feed_watchdog();
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        let param = enforce_vel_limits(motor, param)?;
+        let packet = self.controller.encode_vel(motor, &param);
+        self.collection.send_packet(packet.send_can_id, &packet.data)?;
         Ok(())
     }
 
+    /// Velocity control for all motors.
+    pub fn vel_control_all(&self, params: &[VelParam]) -> Result<()> {
+        self.feed_watchdog();
+        if params.len() != self.motors.len() {
+            return Err(OpenArmError::ParamCountMismatch {
+                expected: self.motors.len(),
+                actual: params.len(),
+            });
+        }
+        let frames: Vec<(u32, [u8; 8])> = self
+            .motors
+            .iter()
+            .zip(params.iter())
+            .map(|(motor, param)| {
+                let param = enforce_vel_limits(motor, param)?;
+                let packet = self.controller.encode_vel(motor, &param);
+                Ok((packet.send_can_id, packet.data.try_into().unwrap()))
+            })
+            .collect::<Result<_>>()?;
+        self.collection.send_batch(&frames, true)
+    }
+
     /// Position-force control for one motor.
     pub fn posforce_control_one(&self, index: usize, param: &PosForceParam) -> Result<()> {
+        self.feed_watchdog();
         let motor = self
             .motors
             .get(index)
             .ok_or(OpenArmError::IndexOutOfRange(index))?;
-        let packet = CanPacketEncoder::create_posforce_control_command(motor, param);
+        let param = enforce_posforce_limits(motor, param)?;
+        let packet = self.controller.encode_posforce(motor, &param);
         self.collection.send_packet(packet.send_can_id, &packet.data)?;
         Ok(())
     }
 
     /// Position-force control for all motors.
     pub fn posforce_control_all(&self, params: &[PosForceParam]) -> Result<()> {
+        self.feed_watchdog();
         if params.len() != self.motors.len() {
             return Err(OpenArmError::ParamCountMismatch {
                 expected: self.motors.len(),
                 actual: params.len(),
             });
         }
-        for (motor, param) in self.motors.iter().zip(params.iter()) {
-            let packet = CanPacketEncoder::create_posforce_control_command(motor, param);
-            self.collection.send_packet(packet.send_can_id, &packet.data)?;
+        let frames: Vec<(u32, [u8; 8])> = self
+            .motors
+            .iter()
+            .zip(params.iter())
+            .map(|(motor, param)| {
+                let param = enforce_posforce_limits(motor, param)?;
+                let packet = self.controller.encode_posforce(motor, &param);
+                Ok((packet.send_can_id, packet.data.try_into().unwrap()))
+            })
+            .collect::<Result<_>>()?;
+        self.collection.send_batch(&frames, true)
+    }
+
+    /// Stream a time-synchronized trapezoidal trajectory to every motor.
+    ///
+    /// Each joint is given a trapezoidal velocity profile (accelerate to
+    /// `max_velocity`, cruise, decelerate) clamped to its `MotorType` limits.
+    /// Every profile is stretched to the slowest joint's total duration so all
+    /// joints start and finish together, then interpolated MIT setpoints are
+    /// dispatched as one synchronized batch every `period_us` microseconds.
+    /// Blocks until the motion completes.
+    pub fn run_trajectory(
+        &self,
+        waypoints: &[Waypoint],
+        period_us: u64,
+        kp: f64,
+        kd: f64,
+    ) -> Result<()> {
+        if waypoints.len() != self.motors.len() {
+            return Err(OpenArmError::ParamCountMismatch {
+                expected: self.motors.len(),
+                actual: waypoints.len(),
+            });
+        }
+
+        // Plan each joint: clamp target/velocity/acceleration to motor limits.
+        let mut plans: Vec<(f64, f64, f64, f64, f64)> = Vec::with_capacity(waypoints.len());
+        for (motor, wp) in self.motors.iter().zip(waypoints.iter()) {
+            let limits = motor.motor_type().get_limits();
+            let start = motor.get_position();
+            let target = wp.position.clamp(-limits.p_max, limits.p_max);
+            let distance = (target - start).abs();
+            let sign = if target >= start { 1.0 } else { -1.0 };
+            let v_max = wp
+                .max_velocity
+                .unwrap_or(limits.v_max * 0.5)
+                .clamp(1e-6, limits.v_max);
+            let a_max = wp.max_acceleration.unwrap_or(v_max * 4.0).max(1e-6);
+            plans.push((start, sign, distance, v_max, a_max));
+        }
+
+        // Synchronize on the slowest joint's minimum-time profile.
+        let total_time = plans
+            .iter()
+            .map(|&(_, _, distance, v_max, a_max)| min_trapezoid_time(distance, v_max, a_max))
+            .fold(0.0_f64, f64::max);
+        if total_time <= 0.0 {
+            return Ok(());
+        }
+
+        let profiles: Vec<TrapezoidProfile> = plans
+            .iter()
+            .map(|&(start, sign, distance, _v_max, a_max)| {
+                stretch_trapezoid(start, sign, distance, a_max, total_time)
+            })
+            .collect();
+
+        let period = Duration::from_micros(period_us);
+        let started = Instant::now();
+        loop {
+            let t = started.elapsed().as_secs_f64().min(total_time);
+            let params: Vec<MITParam> = profiles
+                .iter()
+                .map(|profile| MITParam {
+                    kp,
+                    kd,
+                    q: profile.position_at(t),
+                    dq: 0.0,
+                    tau: 0.0,
+                })
+                .collect();
+            self.mit_control_all(&params)?;
+            if t >= total_time {
+                break;
+            }
+            std::thread::sleep(period);
         }
         Ok(())
     }
@@ -230,7 +873,11 @@ impl DMDeviceCollection {
 
     /// Receive all available frames.
     pub fn recv_all(&self, first_timeout_us: u64) -> Result<usize> {
-        self.collection.recv_all(first_timeout_us)
+        let count = self.collection.recv_all(first_timeout_us)?;
+        if self.safety.lock().unwrap().enabled {
+            self.poll_safety()?;
+        }
+        Ok(count)
     }
 }
 
@@ -240,19 +887,70 @@ pub struct AnyDMDeviceCollection {
     motors: Vec<Motor>,
     devices: Vec<Arc<Mutex<MotorDeviceCan>>>,
     pub(crate) collection: Arc<crate::canbus::AnyCANDeviceCollection>,
+    fault_policy: Mutex<FaultPolicy>,
+    controller: Box<dyn MotorController>,
 }
 
 #[cfg(feature = "remote")]
 impl AnyDMDeviceCollection {
-    /// Create from shared collection.
+    /// Create from shared collection, using the Damiao command encoding.
     pub fn from_collection(collection: Arc<crate::canbus::AnyCANDeviceCollection>) -> Self {
+        Self::with_controller(collection, Box::new(DamiaoController))
+    }
+
+    /// Create from shared collection with a custom motor controller.
+    pub fn with_controller(
+        collection: Arc<crate::canbus::AnyCANDeviceCollection>,
+        controller: Box<dyn MotorController>,
+    ) -> Self {
         Self {
             motors: Vec::new(),
             devices: Vec::new(),
             collection,
+            fault_policy: Mutex::new(FaultPolicy::Report),
+            controller,
         }
     }
 
+    /// Set the policy applied to motors that go stale or fault.
+    pub fn set_fault_policy(&self, policy: FaultPolicy) {
+        *self.fault_policy.lock().unwrap() = policy;
+    }
+
+    /// Report motors that have gone stale (no state frame within `timeout`) or
+    /// are reporting a fault code, stamped from the cached Damiao feedback that
+    /// `recv_all` ingests. When the fault policy is `DisableOnLoss`, each
+    /// reported motor is disabled through the normal disable path.
+    pub fn check_liveness(&self, timeout: Duration) -> Vec<(usize, MotorHealth)> {
+        let now = Instant::now();
+        let mut report = Vec::new();
+        for (index, motor) in self.motors.iter().enumerate() {
+            let health = match motor.last_seen() {
+                Some(seen) if now.duration_since(seen) <= timeout => {
+                    let error_state = motor.get_error_state();
+                    if error_state >= DM_FAULT_STATE_MIN {
+                        Some(MotorHealth::Fault { error_state })
+                    } else {
+                        None
+                    }
+                }
+                _ => Some(MotorHealth::Stale),
+            };
+            if let Some(health) = health {
+                report.push((index, health));
+            }
+        }
+
+        if *self.fault_policy.lock().unwrap() == FaultPolicy::DisableOnLoss {
+            for &(index, _) in &report {
+                let packet = self.controller.encode_disable(&self.motors[index]);
+                let _ = self.collection.send_packet(packet.send_can_id, &packet.data);
+            }
+        }
+
+        report
+    }
+
     /// Add a motor and its device.
     pub fn add_motor_device(&mut self, motor: Motor, device: Arc<Mutex<MotorDeviceCan>>) {
         self.motors.push(motor);
@@ -286,7 +984,7 @@ impl AnyDMDeviceCollection {
     /// Enable all motors.
     pub fn enable_all(&self) -> Result<()> {
         for motor in &self.motors {
-            let packet = CanPacketEncoder::create_enable_command(motor);
+            let packet = self.controller.encode_enable(motor);
             self.collection.send_packet(packet.send_can_id, &packet.data)?;
         }
         Ok(())
@@ -295,7 +993,7 @@ impl AnyDMDeviceCollection {
     /// Disable all motors.
     pub fn disable_all(&self) -> Result<()> {
         for motor in &self.motors {
-            let packet = CanPacketEncoder::create_disable_command(motor);
+            let packet = self.controller.encode_disable(motor);
             self.collection.send_packet(packet.send_can_id, &packet.data)?;
         }
         Ok(())
@@ -304,7 +1002,7 @@ impl AnyDMDeviceCollection {
     /// Set zero position for all motors.
     pub fn set_zero_all(&self) -> Result<()> {
         for motor in &self.motors {
-            let packet = CanPacketEncoder::create_set_zero_command(motor);
+            let packet = self.controller.encode_set_zero(motor);
             self.collection.send_packet(packet.send_can_id, &packet.data)?;
         }
         Ok(())
@@ -330,10 +1028,39 @@ impl AnyDMDeviceCollection {
         Ok(())
     }
 
+    /// Clear the amplifier fault latch for all motors.
+    pub fn clear_faults_all(&self) -> Result<()> {
+        for motor in &self.motors {
+            let packet = CanPacketEncoder::create_clear_error_command(motor);
+            self.collection.send_packet(packet.send_can_id, &packet.data)?;
+        }
+        Ok(())
+    }
+
+    /// Clear the amplifier fault latch for one motor.
+    pub fn clear_faults_one(&self, index: usize) -> Result<()> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        let packet = CanPacketEncoder::create_clear_error_command(motor);
+        self.collection.send_packet(packet.send_can_id, &packet.data)?;
+        Ok(())
+    }
+
+    /// Decode the structured fault status for one motor.
+    pub fn fault_status(&self, index: usize) -> Result<MotorFaultStatus> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        Ok(motor.fault_status())
+    }
+
     /// Query parameter for all motors.
     pub fn query_param_all(&self, rid: MotorVariable) -> Result<()> {
         for motor in &self.motors {
-            let packet = CanPacketEncoder::create_query_param_command(motor, rid);
+            let packet = self.controller.encode_query_param(motor, rid);
             self.collection.send_packet(packet.send_can_id, &packet.data)?;
         }
         Ok(())
@@ -345,18 +1072,147 @@ impl AnyDMDeviceCollection {
             .motors
             .get(index)
             .ok_or(OpenArmError::IndexOutOfRange(index))?;
-        let packet = CanPacketEncoder::create_query_param_command(motor, rid);
+        let packet = self.controller.encode_query_param(motor, rid);
         self.collection.send_packet(packet.send_can_id, &packet.data)?;
         Ok(())
     }
 
+    /// Write a typed parameter to one motor's register.
+    pub fn write_param_one(
+        &self,
+        index: usize,
+        rid: MotorVariable,
+        value: ParamValue,
+    ) -> Result<()> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        let packet = CanPacketEncoder::create_write_param_command(motor, rid, value);
+        self.collection.send_packet(packet.send_can_id, &packet.data)
+    }
+
+    /// Write a typed parameter to every motor's register.
+    pub fn write_param_all(&self, rid: MotorVariable, value: ParamValue) -> Result<()> {
+        for motor in &self.motors {
+            let packet = CanPacketEncoder::create_write_param_command(motor, rid, value);
+            self.collection.send_packet(packet.send_can_id, &packet.data)?;
+        }
+        Ok(())
+    }
+
+    /// Read a parameter synchronously: send the query, drive `recv_all`, and
+    /// return the decoded value once the response for this motor's receive CAN
+    /// id and register id arrives, or time out.
+    pub fn read_param_one(
+        &self,
+        index: usize,
+        rid: MotorVariable,
+        timeout: Duration,
+    ) -> Result<ParamValue> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        let device = self
+            .devices
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+
+        let prev_mode = device.lock().unwrap().get_callback_mode();
+        device.lock().unwrap().set_callback_mode(CallbackMode::PARAM);
+        motor.clear_temp_param(rid as i32);
+
+        let query = self.controller.encode_query_param(motor, rid);
+        let result = self
+            .collection
+            .send_packet(query.send_can_id, &query.data)
+            .and_then(|()| read_param_poll(motor, rid, timeout, |us| self.collection.recv_all(us)));
+
+        device.lock().unwrap().set_callback_mode(prev_mode);
+        result
+    }
+
+    /// Write a register by value, encoding `value` in the register's wire type
+    /// and returning the acknowledged [`ParamResult`].
+    ///
+    /// The value is encoded as u32/i32/f32 according to [`MotorVariable::wire_type`];
+    /// read-only registers are rejected with [`OpenArmError::ReadOnlyRegister`].
+    pub fn set_param_one(
+        &self,
+        index: usize,
+        rid: MotorVariable,
+        value: f64,
+        timeout: Duration,
+    ) -> Result<ParamResult> {
+        if !rid.is_writable() {
+            return Err(OpenArmError::ReadOnlyRegister(rid as i32));
+        }
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        let device = self
+            .devices
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+
+        let encoded = ParamValue::from_f64(rid.wire_type(), value);
+
+        let prev_mode = device.lock().unwrap().get_callback_mode();
+        device.lock().unwrap().set_callback_mode(CallbackMode::PARAM);
+        motor.clear_temp_param(rid as i32);
+
+        let write = CanPacketEncoder::create_write_param_command(motor, rid, encoded);
+        let result = self
+            .collection
+            .send_packet(write.send_can_id, &write.data)
+            .and_then(|()| read_param_poll(motor, rid, timeout, |us| self.collection.recv_all(us)))
+            .map(|echoed| ParamResult {
+                rid: rid as i32,
+                value: echoed.as_f64(),
+                valid: true,
+            });
+
+        device.lock().unwrap().set_callback_mode(prev_mode);
+        result
+    }
+
+    /// Write a register by value to every motor (fire-and-forget).
+    pub fn set_param_all(&self, rid: MotorVariable, value: f64) -> Result<()> {
+        if !rid.is_writable() {
+            return Err(OpenArmError::ReadOnlyRegister(rid as i32));
+        }
+        let encoded = ParamValue::from_f64(rid.wire_type(), value);
+        for motor in &self.motors {
+            let packet = CanPacketEncoder::create_write_param_command(motor, rid, encoded);
+            self.collection.send_packet(packet.send_can_id, &packet.data)?;
+        }
+        Ok(())
+    }
+
+    /// Snapshot the latest decoded feedback for one motor.
+    pub fn telemetry_one(&self, index: usize) -> Result<MotorTelemetry> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        Ok(MotorTelemetry::from_motor(motor))
+    }
+
+    /// Snapshot the latest decoded feedback for every motor.
+    pub fn telemetry_all(&self) -> Vec<MotorTelemetry> {
+        self.motors.iter().map(MotorTelemetry::from_motor).collect()
+    }
+
     /// MIT control for one motor.
     pub fn mit_control_one(&self, index: usize, param: &MITParam) -> Result<()> {
         let motor = self
             .motors
             .get(index)
             .ok_or(OpenArmError::IndexOutOfRange(index))?;
-        let packet = CanPacketEncoder::create_mit_control_command(motor, param);
+        let param = enforce_mit_limits(motor, param)?;
+        let packet = self.controller.encode_mit(motor, &param);
         self.collection.send_packet(packet.send_can_id, &packet.data)?;
         Ok(())
     }
@@ -369,11 +1225,17 @@ impl AnyDMDeviceCollection {
                 actual: params.len(),
             });
         }
-        for (motor, param) in self.motors.iter().zip(params.iter()) {
-            let packet = CanPacketEncoder::create_mit_control_command(motor, param);
-            self.collection.send_packet(packet.send_can_id, &packet.data)?;
-        }
-        Ok(())
+        let frames: Vec<(u32, [u8; 8])> = self
+            .motors
+            .iter()
+            .zip(params.iter())
+            .map(|(motor, param)| {
+                let param = enforce_mit_limits(motor, param)?;
+                let packet = self.controller.encode_mit(motor, &param);
+                Ok((packet.send_can_id, packet.data.try_into().unwrap()))
+            })
+            .collect::<Result<_>>()?;
+        self.collection.send_batch(&frames, true)
     }
 
     /// Position-velocity control for one motor.
@@ -382,7 +1244,8 @@ impl AnyDMDeviceCollection {
             .motors
             .get(index)
             .ok_or(OpenArmError::IndexOutOfRange(index))?;
-        let packet = CanPacketEncoder::create_posvel_control_command(motor, param);
+        let param = enforce_posvel_limits(motor, param)?;
+        let packet = self.controller.encode_posvel(motor, &param);
         self.collection.send_packet(packet.send_can_id, &packet.data)?;
         Ok(())
     }
@@ -395,20 +1258,60 @@ impl AnyDMDeviceCollection {
                 actual: params.len(),
             });
         }
-        for (motor, param) in self.motors.iter().zip(params.iter()) {
-            let packet = CanPacketEncoder::create_posvel_control_command(motor, param);
-            self.collection.send_packet(packet.send_can_id, &packet.data)?;
-        }
+        let frames: Vec<(u32, [u8; 8])> = self
+            .motors
+            .iter()
+            .zip(params.iter())
+            .map(|(motor, param)| {
+                let param = enforce_posvel_limits(motor, param)?;
+                let packet = self.controller.encode_posvel(motor, &param);
+                Ok((packet.send_can_id, packet.data.try_into().unwrap()))
+            })
+            .collect::<Result<_>>()?;
+        self.collection.send_batch(&frames, true)
+    }
+
+    /// Velocity control for one motor.
+    pub fn vel_control_one(&self, index: usize, param: &VelParam) -> Result<()> {
+        let motor = self
+            .motors
+            .get(index)
+            .ok_or(OpenArmError::IndexOutOfRange(index))?;
+        let param = enforce_vel_limits(motor, param)?;
+        let packet = self.controller.encode_vel(motor, &param);
+        self.collection.send_packet(packet.send_can_id, &packet.data)?;
         Ok(())
     }
 
+    /// Velocity control for all motors.
+    pub fn vel_control_all(&self, params: &[VelParam]) -> Result<()> {
+        if params.len() != self.motors.len() {
+            return Err(OpenArmError::ParamCountMismatch {
+                expected: self.motors.len(),
+                actual: params.len(),
+            });
+        }
+        let frames: Vec<(u32, [u8; 8])> = self
+            .motors
+            .iter()
+            .zip(params.iter())
+            .map(|(motor, param)| {
+                let param = enforce_vel_limits(motor, param)?;
+                let packet = self.controller.encode_vel(motor, &param);
+                Ok((packet.send_can_id, packet.data.try_into().unwrap()))
+            })
+            .collect::<Result<_>>()?;
+        self.collection.send_batch(&frames, true)
+    }
+
     /// Position-force control for one motor.
     pub fn posforce_control_one(&self, index: usize, param: &PosForceParam) -> Result<()> {
         let motor = self
             .motors
             .get(index)
             .ok_or(OpenArmError::IndexOutOfRange(index))?;
-        let packet = CanPacketEncoder::create_posforce_control_command(motor, param);
+        let param = enforce_posforce_limits(motor, param)?;
+        let packet = self.controller.encode_posforce(motor, &param);
         self.collection.send_packet(packet.send_can_id, &packet.data)?;
         Ok(())
     }
@@ -421,11 +1324,17 @@ impl AnyDMDeviceCollection {
                 actual: params.len(),
             });
         }
-        for (motor, param) in self.motors.iter().zip(params.iter()) {
-            let packet = CanPacketEncoder::create_posforce_control_command(motor, param);
-            self.collection.send_packet(packet.send_can_id, &packet.data)?;
-        }
-        Ok(())
+        let frames: Vec<(u32, [u8; 8])> = self
+            .motors
+            .iter()
+            .zip(params.iter())
+            .map(|(motor, param)| {
+                let param = enforce_posforce_limits(motor, param)?;
+                let packet = self.controller.encode_posforce(motor, &param);
+                Ok((packet.send_can_id, packet.data.try_into().unwrap()))
+            })
+            .collect::<Result<_>>()?;
+        self.collection.send_batch(&frames, true)
     }
 
     /// Set control mode for one motor.
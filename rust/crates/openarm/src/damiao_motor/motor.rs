@@ -2,8 +2,49 @@
 
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use super::constants::{ControlMode, MotorType};
+use super::control::MotorFaultStatus;
+use crate::error::{OpenArmError, Result};
+
+/// A closed soft-limit range `[min, max]` for a command quantity.
+///
+/// The default range is unbounded, so a freshly constructed [`Motor`] enforces
+/// nothing until [`set_limits`](Motor::set_limits) is called.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limit {
+    /// Lower bound (inclusive).
+    pub min: f64,
+    /// Upper bound (inclusive).
+    pub max: f64,
+}
+
+impl Default for Limit {
+    fn default() -> Self {
+        Self {
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+        }
+    }
+}
+
+impl Limit {
+    /// Create a new limit range.
+    pub fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// Whether `value` lies within the range.
+    pub fn contains(&self, value: f64) -> bool {
+        value >= self.min && value <= self.max
+    }
+
+    /// Clamp `value` into the range.
+    pub fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.min, self.max)
+    }
+}
 
 /// Internal motor state (protected by mutex).
 #[derive(Debug, Clone)]
@@ -14,6 +55,11 @@ pub struct MotorState {
     pub t_mos: i32,
     pub t_rotor: i32,
     pub enabled: bool,
+    /// Status/error nibble from the most recent state frame (0 = disabled,
+    /// 1 = enabled, >= 8 = a Damiao fault code).
+    pub error_state: u8,
+    /// Instant the most recent state frame was ingested, if any.
+    pub last_seen: Option<Instant>,
     pub temp_param_dict: HashMap<i32, f64>,
 }
 
@@ -26,6 +72,8 @@ impl Default for MotorState {
             t_mos: 0,
             t_rotor: 0,
             enabled: false,
+            error_state: 0,
+            last_seen: None,
             temp_param_dict: HashMap::new(),
         }
     }
@@ -38,6 +86,18 @@ pub struct Motor {
     send_can_id: u32,
     recv_can_id: u32,
     control_mode: ControlMode,
+    /// Raw-frame zero offset (rad), added after the reduction ratio is applied.
+    offset: f64,
+    /// Gearbox reduction ratio between the motor and the logical joint.
+    reduction_ratio: f64,
+    /// Soft limit on logical position commands (rad).
+    position_limit: Limit,
+    /// Soft limit on logical velocity commands (rad/s).
+    velocity_limit: Limit,
+    /// Soft limit on logical torque commands (Nm).
+    torque_limit: Limit,
+    /// When true, out-of-range commands error instead of being clamped.
+    strict_limits: bool,
     pub(crate) state: Arc<Mutex<MotorState>>,
 }
 
@@ -54,6 +114,12 @@ impl Motor {
             send_can_id,
             recv_can_id,
             control_mode,
+            offset: 0.0,
+            reduction_ratio: 1.0,
+            position_limit: Limit::default(),
+            velocity_limit: Limit::default(),
+            torque_limit: Limit::default(),
+            strict_limits: false,
             state: Arc::new(Mutex::new(MotorState::default())),
         }
     }
@@ -83,21 +149,139 @@ impl Motor {
         self.control_mode = mode;
     }
 
-    /// Get the current position (rad).
-    pub fn get_position(&self) -> f64 {
+    /// Get the raw-frame zero offset (rad).
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Set the raw-frame zero offset (rad).
+    pub fn set_offset(&mut self, offset: f64) {
+        self.offset = offset;
+    }
+
+    /// Get the gearbox reduction ratio.
+    pub fn reduction_ratio(&self) -> f64 {
+        self.reduction_ratio
+    }
+
+    /// Set the gearbox reduction ratio.
+    pub fn set_reduction_ratio(&mut self, reduction_ratio: f64) {
+        self.reduction_ratio = reduction_ratio;
+    }
+
+    /// Get the logical position soft limit (rad).
+    pub fn position_limit(&self) -> Limit {
+        self.position_limit
+    }
+
+    /// Get the logical velocity soft limit (rad/s).
+    pub fn velocity_limit(&self) -> Limit {
+        self.velocity_limit
+    }
+
+    /// Get the logical torque soft limit (Nm).
+    pub fn torque_limit(&self) -> Limit {
+        self.torque_limit
+    }
+
+    /// Configure the soft limits on logical position, velocity, and torque
+    /// commands. Limits are applied at the command boundary before a value is
+    /// mapped into the raw motor frame.
+    pub fn set_limits(&mut self, position: Limit, velocity: Limit, torque: Limit) {
+        self.position_limit = position;
+        self.velocity_limit = velocity;
+        self.torque_limit = torque;
+    }
+
+    /// Whether out-of-range commands error instead of being clamped.
+    pub fn strict_limits(&self) -> bool {
+        self.strict_limits
+    }
+
+    /// Enable or disable strict limit enforcement. In strict mode a command
+    /// outside the configured range returns [`OpenArmError::CommandOutOfRange`]
+    /// instead of being silently clamped.
+    pub fn set_strict_limits(&mut self, strict: bool) {
+        self.strict_limits = strict;
+    }
+
+    /// Apply `limit` to `value`, returning the clamped value or, in strict
+    /// mode, an error when the value falls outside the range.
+    fn enforce(&self, quantity: &'static str, limit: Limit, value: f64) -> Result<f64> {
+        if self.strict_limits && !limit.contains(value) {
+            return Err(OpenArmError::CommandOutOfRange {
+                quantity,
+                value,
+                min: limit.min,
+                max: limit.max,
+            });
+        }
+        Ok(limit.clamp(value))
+    }
+
+    /// Enforce the position soft limit on a logical position command.
+    pub fn enforce_position(&self, value: f64) -> Result<f64> {
+        self.enforce("position", self.position_limit, value)
+    }
+
+    /// Enforce the velocity soft limit on a logical velocity command.
+    pub fn enforce_velocity(&self, value: f64) -> Result<f64> {
+        self.enforce("velocity", self.velocity_limit, value)
+    }
+
+    /// Enforce the torque soft limit on a logical torque command.
+    pub fn enforce_torque(&self, value: f64) -> Result<f64> {
+        self.enforce("torque", self.torque_limit, value)
+    }
+
+    /// Get the raw position decoded from CAN (rad), before any transform.
+    pub fn get_raw_position(&self) -> f64 {
         self.state.lock().unwrap().position
     }
 
-    /// Get the current velocity (rad/s).
-    pub fn get_velocity(&self) -> f64 {
+    /// Get the raw velocity decoded from CAN (rad/s), before any transform.
+    pub fn get_raw_velocity(&self) -> f64 {
         self.state.lock().unwrap().velocity
     }
 
-    /// Get the current torque (Nm).
-    pub fn get_torque(&self) -> f64 {
+    /// Get the raw torque decoded from CAN (Nm), before any transform.
+    pub fn get_raw_torque(&self) -> f64 {
         self.state.lock().unwrap().torque
     }
 
+    /// Get the logical position (rad): `(raw - offset) / reduction_ratio`.
+    pub fn get_position(&self) -> f64 {
+        (self.get_raw_position() - self.offset) / self.reduction_ratio
+    }
+
+    /// Get the logical velocity (rad/s): `raw / reduction_ratio`.
+    pub fn get_velocity(&self) -> f64 {
+        self.get_raw_velocity() / self.reduction_ratio
+    }
+
+    /// Get the logical torque (Nm): `raw * reduction_ratio`.
+    pub fn get_torque(&self) -> f64 {
+        self.get_raw_torque() * self.reduction_ratio
+    }
+
+    /// Map a logical position command to the raw motor frame:
+    /// `raw = logical * reduction_ratio + offset`.
+    pub fn position_to_raw(&self, logical: f64) -> f64 {
+        logical * self.reduction_ratio + self.offset
+    }
+
+    /// Map a logical velocity command to the raw motor frame:
+    /// `raw = logical * reduction_ratio`.
+    pub fn velocity_to_raw(&self, logical: f64) -> f64 {
+        logical * self.reduction_ratio
+    }
+
+    /// Map a logical torque command to the raw motor frame:
+    /// `raw = logical / reduction_ratio`.
+    pub fn torque_to_raw(&self, logical: f64) -> f64 {
+        logical / self.reduction_ratio
+    }
+
     /// Get the MOS temperature.
     pub fn get_state_tmos(&self) -> i32 {
         self.state.lock().unwrap().t_mos
@@ -108,6 +292,27 @@ impl Motor {
         self.state.lock().unwrap().t_rotor
     }
 
+    /// Get the status/error nibble from the last state frame.
+    pub fn get_error_state(&self) -> u8 {
+        self.state.lock().unwrap().error_state
+    }
+
+    /// Decode the last state frame's status nibble into a structured fault
+    /// status.
+    pub fn fault_status(&self) -> MotorFaultStatus {
+        MotorFaultStatus::from_code(self.state.lock().unwrap().error_state)
+    }
+
+    /// Get the instant the last state frame was ingested, if any.
+    pub fn last_seen(&self) -> Option<Instant> {
+        self.state.lock().unwrap().last_seen
+    }
+
+    /// Take a snapshot clone of the full internal state.
+    pub fn get_state(&self) -> MotorState {
+        self.state.lock().unwrap().clone()
+    }
+
     /// Check if motor is enabled.
     pub fn is_enabled(&self) -> bool {
         self.state.lock().unwrap().enabled
@@ -126,6 +331,7 @@ impl Motor {
         torque: f64,
         t_mos: i32,
         t_rotor: i32,
+        error_state: u8,
     ) {
         let mut state = self.state.lock().unwrap();
         state.position = position;
@@ -133,6 +339,8 @@ impl Motor {
         state.torque = torque;
         state.t_mos = t_mos;
         state.t_rotor = t_rotor;
+        state.error_state = error_state;
+        state.last_seen = Some(Instant::now());
     }
 
     /// Set enabled state.
@@ -144,4 +352,49 @@ impl Motor {
     pub fn set_temp_param(&self, rid: i32, value: f64) {
         self.state.lock().unwrap().temp_param_dict.insert(rid, value);
     }
+
+    /// Logical position as a typed [`Angle`](uom::si::f64::Angle).
+    #[cfg(feature = "units")]
+    pub fn get_position_typed(&self) -> uom::si::f64::Angle {
+        uom::si::f64::Angle::new::<uom::si::angle::radian>(self.get_position())
+    }
+
+    /// Logical velocity as a typed
+    /// [`AngularVelocity`](uom::si::f64::AngularVelocity).
+    #[cfg(feature = "units")]
+    pub fn get_velocity_typed(&self) -> uom::si::f64::AngularVelocity {
+        uom::si::f64::AngularVelocity::new::<uom::si::angular_velocity::radian_per_second>(
+            self.get_velocity(),
+        )
+    }
+
+    /// Logical torque as a typed [`Torque`](uom::si::f64::Torque).
+    #[cfg(feature = "units")]
+    pub fn get_torque_typed(&self) -> uom::si::f64::Torque {
+        uom::si::f64::Torque::new::<uom::si::torque::newton_meter>(self.get_torque())
+    }
+
+    /// MOS temperature as a typed
+    /// [`ThermodynamicTemperature`](uom::si::f64::ThermodynamicTemperature).
+    #[cfg(feature = "units")]
+    pub fn get_tmos_typed(&self) -> uom::si::f64::ThermodynamicTemperature {
+        uom::si::f64::ThermodynamicTemperature::new::<
+            uom::si::thermodynamic_temperature::degree_celsius,
+        >(self.get_state_tmos() as f64)
+    }
+
+    /// Rotor temperature as a typed
+    /// [`ThermodynamicTemperature`](uom::si::f64::ThermodynamicTemperature).
+    #[cfg(feature = "units")]
+    pub fn get_trotor_typed(&self) -> uom::si::f64::ThermodynamicTemperature {
+        uom::si::f64::ThermodynamicTemperature::new::<
+            uom::si::thermodynamic_temperature::degree_celsius,
+        >(self.get_state_trotor() as f64)
+    }
+
+    /// Forget a stored parameter value, so a subsequent read can detect a
+    /// freshly received response.
+    pub fn clear_temp_param(&self, rid: i32) {
+        self.state.lock().unwrap().temp_param_dict.remove(&rid);
+    }
 }
@@ -1,5 +1,6 @@
 //! Error types for OpenArm.
 
+use crate::canbus::socket::CanBusError;
 use thiserror::Error;
 
 /// Errors that can occur in OpenArm operations.
@@ -32,6 +33,51 @@ pub enum OpenArmError {
     /// Parameter count mismatch.
     #[error("Parameter count mismatch: expected {expected}, got {actual}")]
     ParamCountMismatch { expected: usize, actual: usize },
+
+    /// Timed out waiting for a parameter response.
+    #[error("Timed out waiting for response to register {0}")]
+    ResponseTimeout(i32),
+
+    /// A CAN error frame was received from the bus.
+    #[error("CAN bus error: {0:?}")]
+    BusError(CanBusError),
+
+    /// Attempted to write a read-only register.
+    #[error("Register {0} is read-only")]
+    ReadOnlyRegister(i32),
+
+    /// Failed to load or parse an arm configuration file.
+    #[error("Configuration error: {0}")]
+    ConfigError(String),
+
+    /// A command was rejected by the safety supervisor for violating a
+    /// configured per-joint limit.
+    #[error("joint {joint} {quantity} command {value} violates safety limit [{min}, {max}]")]
+    LimitViolation {
+        /// Index of the offending arm joint.
+        joint: usize,
+        /// Which quantity was out of range (e.g. "position").
+        quantity: &'static str,
+        /// The offending command value.
+        value: f64,
+        /// Configured lower bound.
+        min: f64,
+        /// Configured upper bound.
+        max: f64,
+    },
+
+    /// A command value exceeded the configured soft limit in strict mode.
+    #[error("{quantity} command {value} out of range [{min}, {max}]")]
+    CommandOutOfRange {
+        /// Which quantity was out of range (e.g. "position").
+        quantity: &'static str,
+        /// The offending command value.
+        value: f64,
+        /// Configured lower bound.
+        min: f64,
+        /// Configured upper bound.
+        max: f64,
+    },
 }
 
 /// Result type for OpenArm operations.
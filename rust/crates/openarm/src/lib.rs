@@ -17,17 +17,33 @@ pub mod damiao_motor;
 pub mod error;
 
 // Re-export main types for convenience
-pub use canbus::{CANDevice, CANDeviceCollection, CANDeviceTrait, CANSocket, CanFdFrame, CanFrame, MotorDeviceCan};
+pub use canbus::{
+    CANDevice, CANDeviceCollection, CANDeviceTrait, CANSocket, CanFdFrame, CanFrame,
+    FakeMotorDeviceCan, MotorDeviceCan,
+    CaptureLog, CapturedFrame, FrameDirection, FrameReceiver, FrameTracer, OverflowPolicy,
+    Protocol, ReassemblyConfig, Replayer, RxToken, SimulatedCanSocket, TaskHandle,
+    VirtualCanBackend,
+};
 
 #[cfg(feature = "remote")]
 pub use canbus::{AnyCANDeviceCollection, AnyCANSocket};
-pub use components::{ArmComponent, GripperComponent, OpenArm};
+pub use components::{
+    ArmComponent, GraspResult, GraspState, GripperComponent, GripperConfig, JointConfig,
+    JointLimits, LimitPolicy, MotorConfig, MotorGroup, MotorGroupConfig, MotorGroupJointConfig,
+    MultiArm, OpenArm, OpenArmConfig, OpenArmInterface, PidGains, RobotConfig, RobotGripperConfig,
+    SafetySupervisor, Trajectory, TrajectoryEvent,
+};
 
 #[cfg(feature = "remote")]
 pub use components::{AnyArmComponent, AnyGripperComponent, RemoteOpenArm};
 pub use damiao_motor::{
-    CANPacket, CallbackMode, CanPacketDecoder, CanPacketEncoder, ControlMode, DMDeviceCollection,
-    LimitParam, MITParam, Motor, MotorState, MotorStateResult, MotorType, MotorVariable, ParamResult,
-    PosForceParam, PosVelParam,
+    deg_to_rad, protocol_for, rad_to_deg, CANPacket, CallbackMode, CanPacketDecoder,
+    CanPacketEncoder, ControlMode,
+    CyberGearProtocol, DamiaoController, DamiaoProtocol, DMDeviceCollection, FaultPolicy,
+    Limit, LimitParam, MITParam, Motor, MotorController, MotorError, MotorFamily,
+    MotorFaultStatus, MotorHealth,
+    MotorProtocol, MotorState, MotorStateResult, MotorTelemetry, MotorType, MotorVariable,
+    ParamResult, ParamValue, ParamWireType, PosForceParam, PosVelParam, SafetyTrip, VelParam,
+    Waypoint,
 };
 pub use error::{OpenArmError, Result};
@@ -4,6 +4,7 @@ use pyo3::exceptions::PyException;
 use pyo3::prelude::*;
 use socketcan::{CanFdSocket, CanSocket, EmbeddedFrame, Frame, Socket};
 use std::os::unix::io::AsRawFd;
+use std::sync::atomic::{AtomicU8, Ordering};
 use std::time::Duration;
 
 // Python exception for CAN socket errors.
@@ -15,21 +16,51 @@ pyo3::create_exception!(openarm_can, CANSocketException, PyException);
 pub struct CanFrame {
     pub can_id: u32,
     pub data: Vec<u8>,
+    /// Whether `can_id` is a 29-bit extended identifier.
+    pub is_extended: bool,
+    /// Kernel receive timestamp in nanoseconds, populated by
+    /// [`CANSocket::read_can_frame_ts`] when timestamping is enabled; `None`
+    /// otherwise.
+    pub timestamp_ns: Option<u64>,
 }
 
 #[pymethods]
 impl CanFrame {
     #[new]
-    #[pyo3(signature = (can_id, data))]
-    pub fn new(can_id: u32, data: Vec<u8>) -> Self {
-        Self { can_id, data }
+    #[pyo3(signature = (can_id, data, is_extended=None))]
+    pub fn new(can_id: u32, data: Vec<u8>, is_extended: Option<bool>) -> Self {
+        Self {
+            can_id,
+            data,
+            // Default to extended form when the id does not fit the 11-bit
+            // standard range, so callers rarely need to pass the flag.
+            is_extended: is_extended.unwrap_or(can_id > 0x7FF),
+            timestamp_ns: None,
+        }
     }
 
     fn __repr__(&self) -> String {
-        format!("CanFrame(can_id=0x{:X}, data={:?})", self.can_id, self.data)
+        format!(
+            "CanFrame(can_id=0x{:X}, data={:?}, is_extended={})",
+            self.can_id, self.data, self.is_extended
+        )
     }
 }
 
+/// Bit values for the [`CanFdFrame::flags`] field.
+///
+/// These mirror the data-phase attributes a CAN-FD controller exposes: the
+/// bit-rate-switch bit selects the faster data-phase bitrate, and the
+/// error-state-indicator bit reports whether the transmitter was error-active.
+pub struct CanFdFlags;
+
+impl CanFdFlags {
+    /// Bit-rate switch: run the data phase at the faster bitrate.
+    pub const BRS: u8 = 0x01;
+    /// Error-state indicator: transmitter was error-passive.
+    pub const ESI: u8 = 0x02;
+}
+
 /// CAN-FD frame wrapper for Python.
 #[pyclass(get_all)]
 #[derive(Debug, Clone)]
@@ -37,24 +68,190 @@ pub struct CanFdFrame {
     pub can_id: u32,
     pub data: Vec<u8>,
     pub flags: u8,
+    /// Whether `can_id` is a 29-bit extended identifier.
+    pub is_extended: bool,
+    /// Kernel receive timestamp in nanoseconds, populated by
+    /// [`CANSocket::read_canfd_frame_ts`] when timestamping is enabled; `None`
+    /// otherwise.
+    pub timestamp_ns: Option<u64>,
 }
 
 #[pymethods]
 impl CanFdFrame {
     #[new]
-    #[pyo3(signature = (can_id, data, flags=0))]
-    pub fn new(can_id: u32, data: Vec<u8>, flags: u8) -> Self {
-        Self { can_id, data, flags }
+    #[pyo3(signature = (can_id, data, flags=0, is_extended=None))]
+    pub fn new(can_id: u32, data: Vec<u8>, flags: u8, is_extended: Option<bool>) -> Self {
+        Self {
+            can_id,
+            data,
+            flags,
+            is_extended: is_extended.unwrap_or(can_id > 0x7FF),
+            timestamp_ns: None,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CanFdFrame(can_id=0x{:X}, data={:?}, flags={}, is_extended={})",
+            self.can_id, self.data, self.flags, self.is_extended
+        )
     }
+}
 
+/// Build a socketcan identifier, selecting extended form when requested or when
+/// the value exceeds the 11-bit standard range. Validates that the id fits the
+/// chosen width.
+fn make_can_id(can_id: u32, is_extended: bool) -> PyResult<socketcan::Id> {
+    if is_extended || can_id > 0x7FF {
+        Ok(socketcan::Id::Extended(
+            socketcan::ExtendedId::new(can_id).ok_or_else(|| {
+                CANSocketException::new_err(format!("Invalid extended CAN ID: 0x{:X}", can_id))
+            })?,
+        ))
+    } else {
+        Ok(socketcan::Id::Standard(
+            socketcan::StandardId::new(can_id as u16).ok_or_else(|| {
+                CANSocketException::new_err(format!("Invalid CAN ID: 0x{:X}", can_id))
+            })?,
+        ))
+    }
+}
+
+/// Build a socketcan identifier for the internal raw write paths, which report
+/// `std::io::Error` rather than a Python exception. Selects extended form when
+/// the value exceeds the 11-bit standard range.
+fn make_raw_can_id(can_id: u32) -> std::io::Result<socketcan::Id> {
+    if can_id > 0x7FF {
+        Ok(socketcan::Id::Extended(
+            socketcan::ExtendedId::new(can_id).ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidInput, "Invalid CAN ID")
+            })?,
+        ))
+    } else {
+        Ok(socketcan::Id::Standard(
+            socketcan::StandardId::new(can_id as u16).ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidInput, "Invalid CAN ID")
+            })?,
+        ))
+    }
+}
+
+/// Decode a socketcan identifier into its raw value and extended flag.
+fn decode_can_id(id: socketcan::Id) -> (u32, bool) {
+    match id {
+        socketcan::Id::Standard(s) => (s.as_raw() as u32, false),
+        socketcan::Id::Extended(e) => (e.as_raw(), true),
+    }
+}
+
+/// Controller bus state, derived from received CAN error frames.
+#[pyclass(eq, eq_int)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BusState {
+    /// Error counters are within the normal range.
+    #[default]
+    ErrorActive = 0,
+    /// A counter has crossed the warning limit (96).
+    ErrorWarning = 1,
+    /// A counter has crossed the passive limit (128).
+    ErrorPassive = 2,
+    /// The controller has taken itself off the bus.
+    BusOff = 3,
+}
+
+impl BusState {
+    fn from_u8(v: u8) -> Self {
+        match v {
+            1 => BusState::ErrorWarning,
+            2 => BusState::ErrorPassive,
+            3 => BusState::BusOff,
+            _ => BusState::ErrorActive,
+        }
+    }
+}
+
+/// A decoded CAN error frame.
+///
+/// Parsed from the arbitration-id error class bits and the 8 error data bytes
+/// (see `linux/can/error.h`). Delivered through
+/// [`CANSocket::read_error_frame`] once error reception is enabled via
+/// [`CANSocket::enable_error_frames`].
+#[pyclass(get_all)]
+#[derive(Debug, Clone)]
+pub struct CanErrorFrame {
+    /// Bus-off condition.
+    pub is_bus_off: bool,
+    /// TX timeout reported by the netdevice driver.
+    pub is_tx_timeout: bool,
+    /// Arbitration was lost.
+    pub is_lost_arbitration: bool,
+    /// Controller problem (overrun, error state change).
+    pub is_controller: bool,
+    /// Protocol (frame format) violation.
+    pub is_protocol: bool,
+    /// No acknowledgement on transmission.
+    pub is_ack: bool,
+    /// Controller status byte (`data[1]`, the `CAN_ERR_CRTL_*` bits).
+    pub controller_status: u8,
+    /// Bit position at which arbitration was lost (`data[0]`).
+    pub lost_arbitration_bit: u8,
+    /// Protocol error type (`data[2]`).
+    pub protocol_error_type: u8,
+    /// Protocol error location (`data[3]`).
+    pub protocol_error_location: u8,
+    /// Bus state derived from the controller status.
+    pub bus_state: BusState,
+}
+
+#[pymethods]
+impl CanErrorFrame {
     fn __repr__(&self) -> String {
         format!(
-            "CanFdFrame(can_id=0x{:X}, data={:?}, flags={})",
-            self.can_id, self.data, self.flags
+            "CanErrorFrame(bus_off={}, tx_timeout={}, lost_arb={}, controller={}, protocol={}, ack={})",
+            self.is_bus_off,
+            self.is_tx_timeout,
+            self.is_lost_arbitration,
+            self.is_controller,
+            self.is_protocol,
+            self.is_ack
         )
     }
 }
 
+/// Decode the error class bits and data bytes of a received error frame.
+fn decode_error_frame(can_id: u32, data: &[u8]) -> CanErrorFrame {
+    let class = can_id & libc::CAN_ERR_MASK;
+    let controller_status = data.get(1).copied().unwrap_or(0);
+
+    let bus_state = if class & libc::CAN_ERR_BUSOFF != 0 {
+        BusState::BusOff
+    } else {
+        let passive = (libc::CAN_ERR_CRTL_RX_PASSIVE | libc::CAN_ERR_CRTL_TX_PASSIVE) as u8;
+        let warning = (libc::CAN_ERR_CRTL_RX_WARNING | libc::CAN_ERR_CRTL_TX_WARNING) as u8;
+        if controller_status & passive != 0 {
+            BusState::ErrorPassive
+        } else if controller_status & warning != 0 {
+            BusState::ErrorWarning
+        } else {
+            BusState::ErrorActive
+        }
+    };
+
+    CanErrorFrame {
+        is_bus_off: class & libc::CAN_ERR_BUSOFF != 0,
+        is_tx_timeout: class & libc::CAN_ERR_TX_TIMEOUT != 0,
+        is_lost_arbitration: class & libc::CAN_ERR_LOSTARB != 0,
+        is_controller: class & libc::CAN_ERR_CRTL != 0,
+        is_protocol: class & libc::CAN_ERR_PROT != 0,
+        is_ack: class & libc::CAN_ERR_ACK != 0,
+        controller_status,
+        lost_arbitration_bit: data.first().copied().unwrap_or(0),
+        protocol_error_type: data.get(2).copied().unwrap_or(0),
+        protocol_error_location: data.get(3).copied().unwrap_or(0),
+        bus_state,
+    }
+}
+
 /// Internal socket wrapper to handle both CAN and CAN-FD.
 enum SocketInner {
     Can(CanSocket),
@@ -68,6 +265,13 @@ pub struct CANSocket {
     interface: String,
     enable_fd: bool,
     recv_timeout_us: u64,
+    /// Stored `(can_id, can_mask)` receive filters, re-applied when the socket
+    /// is (re)initialized if the list is non-empty. An empty list is not
+    /// re-applied, so a reinitialized socket then receives every frame.
+    filters: Vec<(u32, u32)>,
+    /// Last controller bus state observed from a received error frame,
+    /// encoded as a [`BusState`] discriminant.
+    last_bus_state: AtomicU8,
 }
 
 #[pymethods]
@@ -80,6 +284,8 @@ impl CANSocket {
             interface,
             enable_fd,
             recv_timeout_us,
+            filters: Vec::new(),
+            last_bus_state: AtomicU8::new(BusState::ErrorActive as u8),
         };
         socket.initialize_socket()?;
         Ok(socket)
@@ -116,6 +322,12 @@ impl CANSocket {
 
             self.inner = Some(SocketInner::Can(sock));
         }
+
+        // Re-install any previously configured kernel filters so they survive a
+        // socket reinitialize.
+        if !self.filters.is_empty() {
+            self.apply_filters()?;
+        }
         Ok(())
     }
 
@@ -148,9 +360,7 @@ impl CANSocket {
         })?;
 
         let can_frame = socketcan::CanFrame::new(
-            socketcan::StandardId::new(frame.can_id as u16).ok_or_else(|| {
-                CANSocketException::new_err(format!("Invalid CAN ID: 0x{:X}", frame.can_id))
-            })?,
+            make_can_id(frame.can_id, frame.is_extended)?,
             &frame.data,
         )
         .ok_or_else(|| CANSocketException::new_err("Failed to create CAN frame"))?;
@@ -183,14 +393,15 @@ impl CANSocket {
                 ))
             }
             SocketInner::CanFd(sock) => {
-                let fd_frame = socketcan::CanFdFrame::new(
-                    socketcan::StandardId::new(frame.can_id as u16).ok_or_else(|| {
-                        CANSocketException::new_err(format!("Invalid CAN ID: 0x{:X}", frame.can_id))
-                    })?,
+                let mut fd_frame = socketcan::CanFdFrame::new(
+                    make_can_id(frame.can_id, frame.is_extended)?,
                     &frame.data,
                 )
                 .ok_or_else(|| CANSocketException::new_err("Failed to create CAN-FD frame"))?;
 
+                fd_frame.set_brs(frame.flags & CanFdFlags::BRS != 0);
+                fd_frame.set_esi(frame.flags & CanFdFlags::ESI != 0);
+
                 sock.write_frame(&fd_frame).map_err(|e| {
                     CANSocketException::new_err(format!("Failed to write CAN-FD frame: {}", e))
                 })?;
@@ -207,10 +418,15 @@ impl CANSocket {
 
         match inner {
             SocketInner::Can(sock) => match sock.read_frame() {
-                Ok(frame) => Ok(Some(CanFrame {
-                    can_id: frame.raw_id(),
-                    data: frame.data().to_vec(),
-                })),
+                Ok(frame) => {
+                    let (can_id, is_extended) = decode_can_id(frame.id());
+                    Ok(Some(CanFrame {
+                        can_id,
+                        data: frame.data().to_vec(),
+                        is_extended,
+                        timestamp_ns: None,
+                    }))
+                }
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(CANSocketException::new_err(format!(
@@ -219,10 +435,15 @@ impl CANSocket {
                 ))),
             },
             SocketInner::CanFd(sock) => match sock.read_frame() {
-                Ok(frame) => Ok(Some(CanFrame {
-                    can_id: frame.raw_id(),
-                    data: frame.data().to_vec(),
-                })),
+                Ok(frame) => {
+                    let (can_id, is_extended) = decode_can_id(frame.id());
+                    Ok(Some(CanFrame {
+                        can_id,
+                        data: frame.data().to_vec(),
+                        is_extended,
+                        timestamp_ns: None,
+                    }))
+                }
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(CANSocketException::new_err(format!(
@@ -244,11 +465,23 @@ impl CANSocket {
                 "CAN-FD frames not supported on standard CAN socket",
             )),
             SocketInner::CanFd(sock) => match sock.read_frame() {
-                Ok(frame) => Ok(Some(CanFdFrame {
-                    can_id: frame.raw_id(),
-                    data: frame.data().to_vec(),
-                    flags: 0, // socketcan crate doesn't expose flags directly
-                })),
+                Ok(frame) => {
+                    let (can_id, is_extended) = decode_can_id(frame.id());
+                    let mut flags = 0u8;
+                    if frame.is_brs() {
+                        flags |= CanFdFlags::BRS;
+                    }
+                    if frame.is_esi() {
+                        flags |= CanFdFlags::ESI;
+                    }
+                    Ok(Some(CanFdFrame {
+                        can_id,
+                        data: frame.data().to_vec(),
+                        flags,
+                        is_extended,
+                        timestamp_ns: None,
+                    }))
+                }
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(CANSocketException::new_err(format!(
@@ -327,6 +560,334 @@ impl CANSocket {
         Ok(())
     }
 
+    /// Install kernel-level receive filters via `CAN_RAW_FILTER`.
+    ///
+    /// Each `(can_id, can_mask)` pair admits a frame when
+    /// `received_id & can_mask == can_id & can_mask`, so the kernel drops
+    /// uninteresting traffic before it ever reaches [`read_raw`](Self::read_raw).
+    /// The filters are stored and, when non-empty, re-applied by
+    /// [`initialize_socket`](Self::initialize_socket). Passing an empty list
+    /// installs a zero-length filter, which drops all incoming frames; it is
+    /// not re-applied, so after a reinitialize the socket receives every frame.
+    pub fn set_filters(&mut self, filters: Vec<(u32, u32)>) -> PyResult<()> {
+        self.filters = filters;
+        self.apply_filters()
+    }
+
+    /// Auto-install filters admitting exactly the given receive ids.
+    ///
+    /// Builds one exact-match rule (`can_mask = CAN_EFF_MASK`) per id — the
+    /// convenience `OpenArm` uses after `init_arm_motors`/`init_gripper_motor`
+    /// to let the kernel pass only the registered motor reply frames. When
+    /// `invert` is true the `CAN_INV_FILTER` bit is set on each rule, turning
+    /// the set into a black-list.
+    #[pyo3(signature = (recv_ids, invert=false))]
+    pub fn set_filter_for_recv_ids(&mut self, recv_ids: Vec<u32>, invert: bool) -> PyResult<()> {
+        let filters = recv_ids
+            .into_iter()
+            .map(|id| {
+                let can_id = if invert {
+                    id | libc::CAN_INV_FILTER
+                } else {
+                    id
+                };
+                (can_id, libc::CAN_EFF_MASK)
+            })
+            .collect();
+        self.set_filters(filters)
+    }
+
+    /// Enable reception of CAN error frames via `CAN_RAW_ERR_FILTER`.
+    ///
+    /// `mask` selects which error classes are delivered (a bitwise-OR of the
+    /// `CAN_ERR_*` class flags, or `CAN_ERR_MASK` for all of them). Error frames
+    /// then surface through [`read_error_frame`](Self::read_error_frame) and
+    /// update the state reported by [`get_bus_state`](Self::get_bus_state).
+    pub fn enable_error_frames(&self, mask: u32) -> PyResult<()> {
+        let fd = self
+            .raw_fd()
+            .ok_or_else(|| CANSocketException::new_err("Socket not open"))?;
+
+        let ret = unsafe {
+            libc::setsockopt(
+                fd,
+                libc::SOL_CAN_RAW,
+                libc::CAN_RAW_ERR_FILTER,
+                &mask as *const u32 as *const libc::c_void,
+                std::mem::size_of_val(&mask) as libc::socklen_t,
+            )
+        };
+
+        if ret < 0 {
+            Err(CANSocketException::new_err(format!(
+                "Failed to enable error frames: {}",
+                std::io::Error::last_os_error()
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Drain one frame, returning it only when it is a CAN error frame.
+    ///
+    /// Reads a raw frame and, when the arbitration id carries `CAN_ERR_FLAG`,
+    /// decodes it into a [`CanErrorFrame`] and records the derived bus state
+    /// (observable via [`get_bus_state`](Self::get_bus_state)). Ordinary data
+    /// frames and read timeouts both yield `None`, so a supervising loop can
+    /// poll this to watch for a degrading bus. Requires error reception to be
+    /// enabled first via [`enable_error_frames`](Self::enable_error_frames).
+    pub fn read_error_frame(&self) -> PyResult<Option<CanErrorFrame>> {
+        let frame = self.recv_raw_frame().map_err(|e| {
+            CANSocketException::new_err(format!("Failed to read error frame: {}", e))
+        })?;
+
+        match frame {
+            Some((raw_id, data)) if raw_id & libc::CAN_ERR_FLAG != 0 => {
+                let error = decode_error_frame(raw_id, &data);
+                self.last_bus_state
+                    .store(error.bus_state as u8, Ordering::Relaxed);
+                Ok(Some(error))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// The most recent controller bus state observed from an error frame.
+    ///
+    /// Defaults to [`BusState::ErrorActive`] until an error frame reporting a
+    /// worse state has been drained by [`read_error_frame`](Self::read_error_frame).
+    pub fn get_bus_state(&self) -> BusState {
+        BusState::from_u8(self.last_bus_state.load(Ordering::Relaxed))
+    }
+
+    /// Enable kernel software receive timestamps on the socket.
+    ///
+    /// Sets `SO_TIMESTAMPNS` on `SOL_SOCKET` so the kernel records a nanosecond
+    /// timestamp for each received frame. Afterwards
+    /// [`read_can_frame_ts`](Self::read_can_frame_ts) and
+    /// [`read_canfd_frame_ts`](Self::read_canfd_frame_ts) populate
+    /// `timestamp_ns` from the `SCM_TIMESTAMPNS` control message.
+    pub fn enable_timestamps(&self) -> PyResult<()> {
+        let fd = self
+            .raw_fd()
+            .ok_or_else(|| CANSocketException::new_err("Socket not open"))?;
+
+        let enable: libc::c_int = 1;
+        let ret = unsafe {
+            libc::setsockopt(
+                fd,
+                libc::SOL_SOCKET,
+                libc::SO_TIMESTAMPNS,
+                &enable as *const libc::c_int as *const libc::c_void,
+                std::mem::size_of_val(&enable) as libc::socklen_t,
+            )
+        };
+
+        if ret < 0 {
+            Err(CANSocketException::new_err(format!(
+                "Failed to enable timestamps: {}",
+                std::io::Error::last_os_error()
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Read a standard CAN frame together with its kernel receive timestamp.
+    ///
+    /// Behaves like [`read_can_frame`](Self::read_can_frame) but uses `recvmsg`
+    /// so the `SCM_TIMESTAMPNS` control message can be recovered into
+    /// `timestamp_ns` (nanoseconds since the Unix epoch). The field is `None`
+    /// when the kernel returned no timestamp (e.g. timestamping was never
+    /// enabled). A timeout still yields `Ok(None)`.
+    pub fn read_can_frame_ts(&self) -> PyResult<Option<CanFrame>> {
+        match self
+            .recv_with_timestamp()
+            .map_err(|e| CANSocketException::new_err(format!("Failed to read CAN frame: {}", e)))?
+        {
+            Some((can_id, is_extended, data, timestamp_ns)) => Ok(Some(CanFrame {
+                can_id,
+                data,
+                is_extended,
+                timestamp_ns,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    /// Read a CAN-FD frame together with its kernel receive timestamp.
+    ///
+    /// The CAN-FD counterpart of [`read_can_frame_ts`](Self::read_can_frame_ts);
+    /// `flags` is left at 0 since the `recvmsg` path does not decode the
+    /// data-phase attributes.
+    pub fn read_canfd_frame_ts(&self) -> PyResult<Option<CanFdFrame>> {
+        match self.recv_with_timestamp().map_err(|e| {
+            CANSocketException::new_err(format!("Failed to read CAN-FD frame: {}", e))
+        })? {
+            Some((can_id, is_extended, data, timestamp_ns)) => Ok(Some(CanFdFrame {
+                can_id,
+                data,
+                flags: 0,
+                is_extended,
+                timestamp_ns,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    /// Send a batch of standard CAN frames in a single `sendmmsg` syscall.
+    ///
+    /// Amortizes the per-frame syscall cost when a whole arm of motors is
+    /// commanded each control cycle. Returns the number of frames actually
+    /// accepted by the kernel. Falls back to looping over
+    /// [`write_can_frame`](Self::write_can_frame) if `sendmmsg` is unavailable
+    /// (`ENOSYS`).
+    pub fn write_frames_batch(&self, frames: Vec<CanFrame>) -> PyResult<usize> {
+        let fd = self
+            .raw_fd()
+            .ok_or_else(|| CANSocketException::new_err("Socket not open"))?;
+        if frames.is_empty() {
+            return Ok(0);
+        }
+
+        let mut can_frames: Vec<libc::can_frame> = Vec::with_capacity(frames.len());
+        for frame in &frames {
+            let mut cf: libc::can_frame = unsafe { std::mem::zeroed() };
+            cf.can_id = if frame.is_extended || frame.can_id > 0x7FF {
+                frame.can_id | libc::CAN_EFF_FLAG
+            } else {
+                frame.can_id
+            };
+            cf.can_dlc = frame.data.len() as u8;
+            cf.data[..frame.data.len()].copy_from_slice(&frame.data);
+            can_frames.push(cf);
+        }
+
+        let mut iovecs: Vec<libc::iovec> = can_frames
+            .iter_mut()
+            .map(|f| libc::iovec {
+                iov_base: f as *mut libc::can_frame as *mut libc::c_void,
+                iov_len: std::mem::size_of::<libc::can_frame>(),
+            })
+            .collect();
+
+        let mut msgs: Vec<libc::mmsghdr> = (0..frames.len())
+            .map(|i| {
+                let mut m: libc::mmsghdr = unsafe { std::mem::zeroed() };
+                m.msg_hdr.msg_iov = &mut iovecs[i];
+                m.msg_hdr.msg_iovlen = 1;
+                m
+            })
+            .collect();
+
+        let n = unsafe {
+            libc::sendmmsg(fd, msgs.as_mut_ptr(), frames.len() as libc::c_uint, 0)
+        };
+
+        if n < 0 {
+            let err = std::io::Error::last_os_error();
+            if err.raw_os_error() == Some(libc::ENOSYS) {
+                // Kernel lacks sendmmsg: fall back to single-frame writes.
+                for frame in &frames {
+                    self.write_can_frame(frame)?;
+                }
+                return Ok(frames.len());
+            }
+            return Err(CANSocketException::new_err(format!(
+                "Failed to send frame batch: {}",
+                err
+            )));
+        }
+
+        Ok(n as usize)
+    }
+
+    /// Receive up to `max` frames in a single `recvmmsg` syscall.
+    ///
+    /// The inverse of [`write_frames_batch`](Self::write_frames_batch). Honors
+    /// the socket's receive timeout and may return fewer than `max` frames (an
+    /// empty vector on timeout). Falls back to looping over
+    /// [`read_can_frame`](Self::read_can_frame) if `recvmmsg` is unavailable
+    /// (`ENOSYS`).
+    pub fn read_frames_batch(&self, max: usize) -> PyResult<Vec<CanFrame>> {
+        let fd = self
+            .raw_fd()
+            .ok_or_else(|| CANSocketException::new_err("Socket not open"))?;
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+
+        let frame_sz = std::mem::size_of::<libc::canfd_frame>();
+        let mut bufs: Vec<Vec<u8>> = vec![vec![0u8; frame_sz]; max];
+        let mut iovecs: Vec<libc::iovec> = bufs
+            .iter_mut()
+            .map(|b| libc::iovec {
+                iov_base: b.as_mut_ptr() as *mut libc::c_void,
+                iov_len: b.len(),
+            })
+            .collect();
+
+        let mut msgs: Vec<libc::mmsghdr> = (0..max)
+            .map(|i| {
+                let mut m: libc::mmsghdr = unsafe { std::mem::zeroed() };
+                m.msg_hdr.msg_iov = &mut iovecs[i];
+                m.msg_hdr.msg_iovlen = 1;
+                m
+            })
+            .collect();
+
+        let n = unsafe {
+            libc::recvmmsg(
+                fd,
+                msgs.as_mut_ptr(),
+                max as libc::c_uint,
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+
+        if n < 0 {
+            let err = std::io::Error::last_os_error();
+            return match err.kind() {
+                std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => Ok(Vec::new()),
+                _ if err.raw_os_error() == Some(libc::ENOSYS) => {
+                    // Kernel lacks recvmmsg: drain one frame at a time.
+                    let mut out = Vec::new();
+                    while out.len() < max {
+                        match self.read_can_frame()? {
+                            Some(frame) => out.push(frame),
+                            None => break,
+                        }
+                    }
+                    Ok(out)
+                }
+                _ => Err(CANSocketException::new_err(format!(
+                    "Failed to receive frame batch: {}",
+                    err
+                ))),
+            };
+        }
+
+        let mut out = Vec::with_capacity(n as usize);
+        for buf in bufs.iter().take(n as usize) {
+            let raw_id = u32::from_ne_bytes([buf[0], buf[1], buf[2], buf[3]]);
+            let is_extended = raw_id & libc::CAN_EFF_FLAG != 0;
+            let can_id = if is_extended {
+                raw_id & libc::CAN_EFF_MASK
+            } else {
+                raw_id & libc::CAN_SFF_MASK
+            };
+            let len = buf[4] as usize;
+            out.push(CanFrame {
+                can_id,
+                data: buf[8..8 + len].to_vec(),
+                is_extended,
+                timestamp_ns: None,
+            });
+        }
+        Ok(out)
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "CANSocket(interface='{}', enable_fd={}, open={})",
@@ -336,6 +897,150 @@ impl CANSocket {
 }
 
 impl CANSocket {
+    /// Apply the stored `CAN_RAW_FILTER` set to the current socket fd.
+    fn apply_filters(&self) -> PyResult<()> {
+        let fd = self
+            .raw_fd()
+            .ok_or_else(|| CANSocketException::new_err("Socket not open"))?;
+
+        let can_filters: Vec<libc::can_filter> = self
+            .filters
+            .iter()
+            .map(|&(can_id, can_mask)| libc::can_filter { can_id, can_mask })
+            .collect();
+
+        // The kernel ORs rules by default, so several inverted rules would
+        // admit every frame; join an all-inverted list so a frame passes only
+        // when it matches none of the ids.
+        let join = (self.filters.len() > 1
+            && self.filters.iter().all(|&(id, _)| id & libc::CAN_INV_FILTER != 0))
+            as libc::c_int;
+        let ret = unsafe {
+            libc::setsockopt(
+                fd,
+                libc::SOL_CAN_RAW,
+                libc::CAN_RAW_JOIN_FILTERS,
+                &join as *const libc::c_int as *const libc::c_void,
+                std::mem::size_of_val(&join) as libc::socklen_t,
+            )
+        };
+        if ret < 0 && join != 0 {
+            return Err(CANSocketException::new_err(format!(
+                "Failed to join CAN filters: {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+
+        let ret = unsafe {
+            libc::setsockopt(
+                fd,
+                libc::SOL_CAN_RAW,
+                libc::CAN_RAW_FILTER,
+                can_filters.as_ptr() as *const libc::c_void,
+                std::mem::size_of_val(can_filters.as_slice()) as libc::socklen_t,
+            )
+        };
+
+        if ret < 0 {
+            Err(CANSocketException::new_err(format!(
+                "Failed to set CAN filters: {}",
+                std::io::Error::last_os_error()
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Receive one raw frame via `recv`, preserving the full arbitration id
+    /// (including the `CAN_ERR_FLAG`/`CAN_EFF_FLAG` bits) so error frames can be
+    /// recognized. Returns `Ok(None)` on a read timeout/would-block.
+    fn recv_raw_frame(&self) -> std::io::Result<Option<(u32, Vec<u8>)>> {
+        let fd = self.raw_fd().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotConnected, "Socket not open")
+        })?;
+
+        let mut buf = [0u8; std::mem::size_of::<libc::canfd_frame>()];
+        let n = unsafe {
+            libc::recv(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len(), 0)
+        };
+
+        if n < 0 {
+            let err = std::io::Error::last_os_error();
+            return match err.kind() {
+                std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => Ok(None),
+                _ => Err(err),
+            };
+        }
+
+        let raw_id = u32::from_ne_bytes([buf[0], buf[1], buf[2], buf[3]]);
+        let len = buf[4] as usize;
+        Ok(Some((raw_id, buf[8..8 + len].to_vec())))
+    }
+
+    /// Receive one frame via `recvmsg`, returning its id, extended flag,
+    /// payload, and the `SCM_TIMESTAMPNS` nanosecond timestamp when present.
+    ///
+    /// Returns `Ok(None)` on a read timeout/would-block, matching the other
+    /// read paths.
+    fn recv_with_timestamp(&self) -> std::io::Result<Option<(u32, bool, Vec<u8>, Option<u64>)>> {
+        let fd = self.raw_fd().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotConnected, "Socket not open")
+        })?;
+
+        // A canfd_frame is a superset of can_frame, and both carry the length
+        // at offset 4 with the payload at offset 8, so one buffer serves both.
+        let mut frame_buf = [0u8; std::mem::size_of::<libc::canfd_frame>()];
+        let mut iov = libc::iovec {
+            iov_base: frame_buf.as_mut_ptr() as *mut libc::c_void,
+            iov_len: frame_buf.len(),
+        };
+
+        // Room for a single SCM_TIMESTAMPNS cmsg plus its header and alignment.
+        let mut cmsg_buf = [0u8; 64];
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = cmsg_buf.as_mut_ptr() as *mut libc::c_void;
+        msg.msg_controllen = cmsg_buf.len() as _;
+
+        let n = unsafe { libc::recvmsg(fd, &mut msg, 0) };
+        if n < 0 {
+            let err = std::io::Error::last_os_error();
+            return match err.kind() {
+                std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => Ok(None),
+                _ => Err(err),
+            };
+        }
+
+        let raw_id = u32::from_ne_bytes([frame_buf[0], frame_buf[1], frame_buf[2], frame_buf[3]]);
+        let len = frame_buf[4] as usize;
+        let data = frame_buf[8..8 + len].to_vec();
+
+        let is_extended = raw_id & libc::CAN_EFF_FLAG != 0;
+        let can_id = if is_extended {
+            raw_id & libc::CAN_EFF_MASK
+        } else {
+            raw_id & libc::CAN_SFF_MASK
+        };
+
+        let mut timestamp_ns = None;
+        let mut cmsg = unsafe { libc::CMSG_FIRSTHDR(&msg) };
+        while !cmsg.is_null() {
+            let hdr = unsafe { &*cmsg };
+            if hdr.cmsg_level == libc::SOL_SOCKET && hdr.cmsg_type == libc::SCM_TIMESTAMPNS {
+                let ts = unsafe {
+                    std::ptr::read_unaligned(libc::CMSG_DATA(cmsg) as *const libc::timespec)
+                };
+                timestamp_ns =
+                    Some(ts.tv_sec as u64 * 1_000_000_000 + ts.tv_nsec as u64);
+                break;
+            }
+            cmsg = unsafe { libc::CMSG_NXTHDR(&msg, cmsg) };
+        }
+
+        Ok(Some((can_id, is_extended, data, timestamp_ns)))
+    }
+
     /// Get raw file descriptor (internal use).
     pub(crate) fn raw_fd(&self) -> Option<i32> {
         self.inner.as_ref().map(|inner| match inner {
@@ -350,13 +1055,7 @@ impl CANSocket {
             std::io::Error::new(std::io::ErrorKind::NotConnected, "Socket not open")
         })?;
 
-        let frame = socketcan::CanFrame::new(
-            socketcan::StandardId::new(can_id as u16).ok_or_else(|| {
-                std::io::Error::new(std::io::ErrorKind::InvalidInput, "Invalid CAN ID")
-            })?,
-            data,
-        )
-        .ok_or_else(|| {
+        let frame = socketcan::CanFrame::new(make_raw_can_id(can_id)?, data).ok_or_else(|| {
             std::io::Error::new(std::io::ErrorKind::InvalidInput, "Failed to create frame")
         })?;
 
@@ -378,15 +1077,13 @@ impl CANSocket {
                 "CAN-FD not supported",
             )),
             SocketInner::CanFd(sock) => {
-                let frame = socketcan::CanFdFrame::new(
-                    socketcan::StandardId::new(can_id as u16).ok_or_else(|| {
-                        std::io::Error::new(std::io::ErrorKind::InvalidInput, "Invalid CAN ID")
-                    })?,
-                    data,
-                )
-                .ok_or_else(|| {
-                    std::io::Error::new(std::io::ErrorKind::InvalidInput, "Failed to create frame")
-                })?;
+                let frame =
+                    socketcan::CanFdFrame::new(make_raw_can_id(can_id)?, data).ok_or_else(|| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            "Failed to create frame",
+                        )
+                    })?;
                 sock.write_frame(&frame)
             }
         }
@@ -400,13 +1097,13 @@ impl CANSocket {
 
         match inner {
             SocketInner::Can(sock) => match sock.read_frame() {
-                Ok(frame) => Ok(Some((frame.raw_id(), frame.data().to_vec()))),
+                Ok(frame) => Ok(Some((decode_can_id(frame.id()).0, frame.data().to_vec()))),
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(e),
             },
             SocketInner::CanFd(sock) => match sock.read_frame() {
-                Ok(frame) => Ok(Some((frame.raw_id(), frame.data().to_vec()))),
+                Ok(frame) => Ok(Some((decode_can_id(frame.id()).0, frame.data().to_vec()))),
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(e),
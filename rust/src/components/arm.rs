@@ -1,18 +1,55 @@
 //! Arm component for controlling multiple arm motors.
 
 use pyo3::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 
 use crate::canbus::{CANDeviceCollection, MotorDeviceCan};
 use crate::damiao_motor::{
-    CallbackMode, ControlMode, DMDeviceCollection, MITParam, Motor, MotorType, MotorVariable,
-    PosForceParam, PosVelParam,
+    CallbackMode, CanPacketEncoder, ClampReport, ControlMode, DMDeviceCollection, MITParam, Motor,
+    MotorFault, MotorType, MotorVariable, PosForceParam, PosVelParam,
 };
 
+/// Snapshot of one motor's state emitted by the reporting thread started with
+/// [`ArmComponent::start_reporting`], including the wall-clock gap since the
+/// previous report so a consumer can detect missed cycles.
+#[pyclass(get_all)]
+#[derive(Debug, Clone, Copy)]
+pub struct MotorReport {
+    pub position: f64,
+    pub velocity: f64,
+    pub torque: f64,
+    pub t_mos: i32,
+    pub t_rotor: i32,
+    pub fault: MotorFault,
+    pub interval_us: u64,
+}
+
+#[pymethods]
+impl MotorReport {
+    fn __repr__(&self) -> String {
+        format!(
+            "MotorReport(position={}, velocity={}, torque={}, t_mos={}, t_rotor={}, fault={:?}, interval_us={})",
+            self.position, self.velocity, self.torque, self.t_mos, self.t_rotor, self.fault, self.interval_us
+        )
+    }
+}
+
+/// Handle to a running background reporting thread.
+struct ReportHandle {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
 /// Arm component wrapper for multiple arm motors.
 #[pyclass]
 pub struct ArmComponent {
     inner: DMDeviceCollection,
+    /// Active background reporting thread started by
+    /// [`start_reporting`](Self::start_reporting), if any.
+    reporting: Mutex<Option<ReportHandle>>,
 }
 
 #[pymethods]
@@ -112,6 +149,12 @@ impl ArmComponent {
         self.inner.mit_control_all(params)
     }
 
+    /// MIT control for one motor, returning a report of which of `param`'s
+    /// fields were saturated against the motor's configured limits.
+    pub fn mit_control_one_checked(&self, index: usize, param: &MITParam) -> PyResult<ClampReport> {
+        self.inner.mit_control_one_checked(index, param)
+    }
+
     /// Position-velocity control for one motor.
     pub fn posvel_control_one(&self, index: usize, param: &PosVelParam) -> PyResult<()> {
         self.inner.posvel_control_one(index, param)
@@ -132,6 +175,108 @@ impl ArmComponent {
         self.inner.posforce_control_all(params)
     }
 
+    /// Write a parameter for one motor.
+    pub fn write_param_one(&self, index: usize, rid: MotorVariable, value: f64) -> PyResult<()> {
+        self.inner.write_param_one(index, rid, value)
+    }
+
+    /// Write a parameter for all motors.
+    pub fn write_param_all(&self, rid: MotorVariable, value: f64) -> PyResult<()> {
+        self.inner.write_param_all(rid, value)
+    }
+
+    /// Persist the current parameter set to flash for all motors.
+    pub fn save_params_all(&self) -> PyResult<()> {
+        self.inner.save_params_all()
+    }
+
+    /// Last queried under-voltage protection threshold (volts), if a query
+    /// for it has been answered.
+    pub fn get_uv_value(&self, index: usize) -> PyResult<Option<f64>> {
+        self.inner.get_uv_value(index)
+    }
+
+    /// Set the under-voltage protection threshold (volts).
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_uv_value(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.inner.set_uv_value(index, value, persist)
+    }
+
+    /// Last queried over-voltage protection threshold (volts), if a query
+    /// for it has been answered.
+    pub fn get_ov_value(&self, index: usize) -> PyResult<Option<f64>> {
+        self.inner.get_ov_value(index)
+    }
+
+    /// Set the over-voltage protection threshold (volts).
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_ov_value(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.inner.set_ov_value(index, value, persist)
+    }
+
+    /// Last queried over-current trip point (amps), if a query for it has
+    /// been answered.
+    pub fn get_oc_value(&self, index: usize) -> PyResult<Option<f64>> {
+        self.inner.get_oc_value(index)
+    }
+
+    /// Set the over-current trip point (amps).
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_oc_value(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.inner.set_oc_value(index, value, persist)
+    }
+
+    /// Last queried over-temperature trip point (degrees C), if a query for
+    /// it has been answered.
+    pub fn get_ot_value(&self, index: usize) -> PyResult<Option<f64>> {
+        self.inner.get_ot_value(index)
+    }
+
+    /// Set the over-temperature trip point (degrees C).
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_ot_value(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.inner.set_ot_value(index, value, persist)
+    }
+
+    /// Last queried torque constant (N*m/A), if a query for it has been
+    /// answered.
+    pub fn get_kt_value(&self, index: usize) -> PyResult<Option<f64>> {
+        self.inner.get_kt_value(index)
+    }
+
+    /// Set the torque constant (N*m/A), bounded by the motor's rated torque
+    /// limit.
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_kt_value(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.inner.set_kt_value(index, value, persist)
+    }
+
+    /// Last queried acceleration limit (rad/s^2), if a query for it has been
+    /// answered.
+    pub fn get_acc(&self, index: usize) -> PyResult<Option<f64>> {
+        self.inner.get_acc(index)
+    }
+
+    /// Set the acceleration limit (rad/s^2), bounded by the motor's velocity
+    /// limit.
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_acc(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.inner.set_acc(index, value, persist)
+    }
+
+    /// Last queried deceleration limit (rad/s^2), if a query for it has been
+    /// answered.
+    pub fn get_dec(&self, index: usize) -> PyResult<Option<f64>> {
+        self.inner.get_dec(index)
+    }
+
+    /// Set the deceleration limit (rad/s^2), bounded by the motor's velocity
+    /// limit.
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_dec(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.inner.set_dec(index, value, persist)
+    }
+
     /// Set control mode for one motor.
     pub fn set_control_mode_one(&self, index: usize, mode: ControlMode) -> PyResult<()> {
         self.inner.set_control_mode_one(index, mode)
@@ -153,6 +298,92 @@ impl ArmComponent {
         self.inner.recv_all(first_timeout_us)
     }
 
+    /// Index and fault of the first motor whose last decoded state reports a
+    /// fault, so callers can abort cleanly instead of continuing to drive a
+    /// tripped joint.
+    pub fn first_fault(&self) -> Option<(usize, MotorFault)> {
+        self.inner.first_fault()
+    }
+
+    /// Start a background thread that loops `refresh_all` + `recv_all` every
+    /// `period_us` microseconds and calls `sink` with the resulting
+    /// `list[MotorReport]` snapshot, in motor order.
+    ///
+    /// `sink` is invoked from the reporting thread, not the caller's, so it
+    /// must be safe to call without holding any lock the caller relies on.
+    /// Calling `start_reporting` while a report thread is already running is
+    /// a no-op; call [`stop_reporting`](Self::stop_reporting) first to change
+    /// the period or sink.
+    pub fn start_reporting(&self, period_us: u64, sink: PyObject) {
+        let mut reporting = self.reporting.lock().unwrap();
+        if reporting.is_some() {
+            return;
+        }
+
+        let motors = self.inner.get_motors();
+        let collection = Arc::clone(self.inner.collection());
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let period = Duration::from_micros(period_us);
+
+        let handle = std::thread::spawn(move || {
+            let mut last = Instant::now();
+            while !thread_stop.load(Ordering::Relaxed) {
+                let cycle_start = Instant::now();
+                for motor in &motors {
+                    let packet = CanPacketEncoder::create_refresh_command(motor);
+                    let _ = collection.send_packet(packet.send_can_id, &packet.data);
+                }
+                let _ = collection.recv_all(period_us);
+
+                let now = Instant::now();
+                let interval_us = now.duration_since(last).as_micros() as u64;
+                last = now;
+
+                let reports: Vec<MotorReport> = motors
+                    .iter()
+                    .map(|motor| {
+                        let state = motor.get_state();
+                        MotorReport {
+                            position: state.position,
+                            velocity: state.velocity,
+                            torque: state.torque,
+                            t_mos: state.t_mos,
+                            t_rotor: state.t_rotor,
+                            fault: state.fault,
+                            interval_us,
+                        }
+                    })
+                    .collect();
+
+                Python::with_gil(|py| {
+                    let _ = sink.call1(py, (reports,));
+                });
+
+                std::thread::sleep(period.saturating_sub(cycle_start.elapsed()));
+            }
+        });
+
+        *reporting = Some(ReportHandle { stop, handle });
+    }
+
+    /// Stop the background reporting thread, if one is running, and wait for
+    /// it to finish.
+    pub fn stop_reporting(&self, py: Python<'_>) {
+        if let Some(report) = self.reporting.lock().unwrap().take() {
+            report.stop.store(true, Ordering::Relaxed);
+            // Release the GIL so a thread waiting to call `sink` can finish.
+            py.allow_threads(move || {
+                let _ = report.handle.join();
+            });
+        }
+    }
+
+    /// Check whether the background reporting thread is running.
+    pub fn is_reporting(&self) -> bool {
+        self.reporting.lock().unwrap().is_some()
+    }
+
     fn __repr__(&self) -> String {
         format!("ArmComponent(motors={})", self.inner.motor_count())
     }
@@ -163,6 +394,7 @@ impl ArmComponent {
     pub(crate) fn from_collection(collection: Arc<CANDeviceCollection>) -> Self {
         Self {
             inner: DMDeviceCollection::from_collection(collection),
+            reporting: Mutex::new(None),
         }
     }
 
@@ -20,6 +20,9 @@ pub struct OpenArm {
     arm: ArmComponent,
     gripper: GripperComponent,
     enable_fd: bool,
+    /// Receive CAN ids of every registered motor, used to auto-install kernel
+    /// filters via [`set_filter_for_recv_ids`](OpenArm::set_filter_for_recv_ids).
+    recv_can_ids: Vec<u32>,
 }
 
 #[pymethods]
@@ -43,6 +46,7 @@ impl OpenArm {
             arm,
             gripper,
             enable_fd,
+            recv_can_ids: Vec::new(),
         })
     }
 
@@ -83,6 +87,7 @@ impl OpenArm {
             self.arm.add_motor_device(motor, device);
         }
 
+        self.recv_can_ids.extend_from_slice(&recv_can_ids);
         Ok(())
     }
 
@@ -104,9 +109,25 @@ impl OpenArm {
         // Add to gripper component
         self.gripper.add_motor_device(motor, device);
 
+        self.recv_can_ids.push(recv_can_id);
         Ok(())
     }
 
+    /// Install kernel receive filters for exactly the registered motor reply
+    /// ids.
+    ///
+    /// Call after [`init_arm_motors`](OpenArm::init_arm_motors) and
+    /// [`init_gripper_motor`](OpenArm::init_gripper_motor) so the kernel drops
+    /// every frame that is not a reply from one of this arm's motors before it
+    /// crosses into Python. Set `invert` to turn the id set into a black-list.
+    #[pyo3(signature = (invert=false))]
+    pub fn set_filter_for_recv_ids(&self, invert: bool) -> PyResult<()> {
+        self.socket
+            .lock()
+            .unwrap()
+            .set_filter_for_recv_ids(self.recv_can_ids.clone(), invert)
+    }
+
     /// Get the arm component.
     pub fn get_arm(&self) -> ArmComponent {
         // Return a new ArmComponent that shares the same collection
@@ -155,6 +155,58 @@ impl MotorType {
     }
 }
 
+/// Amplifier fault/run state packed into the high nibble of a feedback
+/// frame's first data byte (the low nibble carries the low bits of the
+/// reporting motor's CAN id).
+#[pyclass(eq, eq_int)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MotorFault {
+    /// Amplifier disabled (code 0x0).
+    #[default]
+    Disabled = 0x0,
+    /// Amplifier enabled and running (code 0x1).
+    Enabled = 0x1,
+    /// Bus over-voltage (code 0x8).
+    Overvoltage = 0x8,
+    /// Bus under-voltage (code 0x9).
+    Undervoltage = 0x9,
+    /// Over-current (code 0xA).
+    Overcurrent = 0xA,
+    /// MOSFET over-temperature (code 0xB).
+    MosOvertemp = 0xB,
+    /// Rotor over-temperature (code 0xC).
+    RotorOvertemp = 0xC,
+    /// Communication lost (code 0xD).
+    CommLoss = 0xD,
+    /// Overload (code 0xE).
+    Overload = 0xE,
+    /// Any other reported code.
+    Unknown = 0xFF,
+}
+
+impl MotorFault {
+    /// Decode the status nibble reported in a feedback frame's `data[0]`.
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            0x0 => MotorFault::Disabled,
+            0x1 => MotorFault::Enabled,
+            0x8 => MotorFault::Overvoltage,
+            0x9 => MotorFault::Undervoltage,
+            0xA => MotorFault::Overcurrent,
+            0xB => MotorFault::MosOvertemp,
+            0xC => MotorFault::RotorOvertemp,
+            0xD => MotorFault::CommLoss,
+            0xE => MotorFault::Overload,
+            _ => MotorFault::Unknown,
+        }
+    }
+
+    /// Whether this code reports a fault rather than a normal run state.
+    pub fn is_fault(&self) -> bool {
+        !matches!(self, MotorFault::Disabled | MotorFault::Enabled)
+    }
+}
+
 /// Result of a motor state query.
 #[pyclass(get_all)]
 #[derive(Debug, Clone, Copy, Default)]
@@ -164,19 +216,25 @@ pub struct MotorStateResult {
     pub torque: f64,
     pub t_mos: i32,
     pub t_rotor: i32,
+    /// Low bits of the CAN id reported by the responding motor, taken from
+    /// the low nibble of `data[0]`.
+    pub motor_id: u32,
+    pub fault: MotorFault,
     pub valid: bool,
 }
 
 #[pymethods]
 impl MotorStateResult {
     #[new]
-    #[pyo3(signature = (position=0.0, velocity=0.0, torque=0.0, t_mos=0, t_rotor=0, valid=false))]
+    #[pyo3(signature = (position=0.0, velocity=0.0, torque=0.0, t_mos=0, t_rotor=0, motor_id=0, fault=MotorFault::Disabled, valid=false))]
     pub fn new(
         position: f64,
         velocity: f64,
         torque: f64,
         t_mos: i32,
         t_rotor: i32,
+        motor_id: u32,
+        fault: MotorFault,
         valid: bool,
     ) -> Self {
         Self {
@@ -185,14 +243,16 @@ impl MotorStateResult {
             torque,
             t_mos,
             t_rotor,
+            motor_id,
+            fault,
             valid,
         }
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "MotorStateResult(position={}, velocity={}, torque={}, t_mos={}, t_rotor={}, valid={})",
-            self.position, self.velocity, self.torque, self.t_mos, self.t_rotor, self.valid
+            "MotorStateResult(position={}, velocity={}, torque={}, t_mos={}, t_rotor={}, motor_id={}, fault={:?}, valid={})",
+            self.position, self.velocity, self.torque, self.t_mos, self.t_rotor, self.motor_id, self.fault, self.valid
         )
     }
 }
@@ -222,6 +282,70 @@ impl ParamResult {
     }
 }
 
+/// A single control target as sent by the caller and as actually applied
+/// after clamping to the motor's configured limits.
+#[pyclass(get_all)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClampedField {
+    pub original: f64,
+    pub applied: f64,
+    pub saturated: bool,
+}
+
+impl ClampedField {
+    /// Clamp `original` into `[min, max]`, recording whether it had to move.
+    pub fn clamp(original: f64, min: f64, max: f64) -> Self {
+        let applied = original.max(min).min(max);
+        Self {
+            original,
+            applied,
+            saturated: applied != original,
+        }
+    }
+}
+
+#[pymethods]
+impl ClampedField {
+    fn __repr__(&self) -> String {
+        format!(
+            "ClampedField(original={}, applied={}, saturated={})",
+            self.original, self.applied, self.saturated
+        )
+    }
+}
+
+/// Per-field clamp report for a control command, noting which targets (if
+/// any) were saturated against the motor's configured limits. Fields that do
+/// not apply to the command's control mode are `None`.
+#[pyclass(get_all)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ClampReport {
+    pub q: Option<ClampedField>,
+    pub dq: Option<ClampedField>,
+    pub tau: Option<ClampedField>,
+    pub i: Option<ClampedField>,
+}
+
+impl ClampReport {
+    /// Whether any recorded field was saturated.
+    pub fn any_saturated(&self) -> bool {
+        [self.q, self.dq, self.tau, self.i]
+            .into_iter()
+            .flatten()
+            .any(|f| f.saturated)
+    }
+}
+
+#[pymethods]
+impl ClampReport {
+    fn __repr__(&self) -> String {
+        format!(
+            "ClampReport(q={:?}, dq={:?}, tau={:?}, i={:?})",
+            self.q, self.dq, self.tau, self.i
+        )
+    }
+}
+
 /// MIT control parameters.
 #[pyclass(get_all, set_all)]
 #[derive(Debug, Clone, Copy, Default)]
@@ -0,0 +1,409 @@
+//! CAN packet encoding and decoding for the Damiao motor protocol.
+
+use pyo3::prelude::*;
+
+use super::constants::*;
+use super::motor::Motor;
+
+/// Clamp a value to a range.
+fn clamp(value: f64, min: f64, max: f64) -> f64 {
+    value.max(min).min(max)
+}
+
+/// Scale a float to an unsigned integer.
+fn float_to_uint(x: f64, x_min: f64, x_max: f64, bits: u32) -> u32 {
+    let span = x_max - x_min;
+    let offset = x - x_min;
+    let max_val = ((1u64 << bits) - 1) as f64;
+    ((offset / span) * max_val) as u32
+}
+
+/// Scale an unsigned integer to a float.
+fn uint_to_float(x: u32, x_min: f64, x_max: f64, bits: u32) -> f64 {
+    let span = x_max - x_min;
+    let max_val = ((1u64 << bits) - 1) as f64;
+    x_min + (x as f64 / max_val) * span
+}
+
+/// CAN packet encoder for Damiao motor commands.
+#[pyclass]
+#[derive(Debug, Clone, Default)]
+pub struct CanPacketEncoder;
+
+#[pymethods]
+impl CanPacketEncoder {
+    /// Create a new encoder.
+    #[new]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Create enable command.
+    #[staticmethod]
+    pub fn create_enable_command(motor: &Motor) -> CANPacket {
+        CANPacket {
+            send_can_id: motor.send_can_id(),
+            data: vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFC],
+        }
+    }
+
+    /// Create disable command.
+    #[staticmethod]
+    pub fn create_disable_command(motor: &Motor) -> CANPacket {
+        CANPacket {
+            send_can_id: motor.send_can_id(),
+            data: vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFD],
+        }
+    }
+
+    /// Create set zero command (flash current position as zero).
+    #[staticmethod]
+    pub fn create_set_zero_command(motor: &Motor) -> CANPacket {
+        CANPacket {
+            send_can_id: motor.send_can_id(),
+            data: vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE],
+        }
+    }
+
+    /// Create refresh command (request state update).
+    #[staticmethod]
+    pub fn create_refresh_command(motor: &Motor) -> CANPacket {
+        let can_id = motor.send_can_id();
+        CANPacket {
+            send_can_id: 0x7FF,
+            data: vec![
+                (can_id & 0xFF) as u8,
+                ((can_id >> 8) & 0xFF) as u8,
+                0xCC,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+            ],
+        }
+    }
+
+    /// Create MIT control command.
+    #[staticmethod]
+    pub fn create_mit_control_command(motor: &Motor, param: &MITParam) -> CANPacket {
+        let limits = motor.motor_type().get_limits();
+
+        let q_field = ClampedField::clamp(param.q, -limits.p_max, limits.p_max);
+        let dq_field = ClampedField::clamp(param.dq, -limits.v_max, limits.v_max);
+        let tau_field = ClampedField::clamp(param.tau, -limits.t_max, limits.t_max);
+        motor.store_clamp_report(ClampReport {
+            q: Some(q_field),
+            dq: Some(dq_field),
+            tau: Some(tau_field),
+            i: None,
+        });
+
+        let kp = clamp(param.kp, 0.0, 500.0);
+        let kd = clamp(param.kd, 0.0, 5.0);
+
+        let q_int = float_to_uint(q_field.applied, -limits.p_max, limits.p_max, 16);
+        let dq_int = float_to_uint(dq_field.applied, -limits.v_max, limits.v_max, 12);
+        let kp_int = float_to_uint(kp, 0.0, 500.0, 12);
+        let kd_int = float_to_uint(kd, 0.0, 5.0, 12);
+        let tau_int = float_to_uint(tau_field.applied, -limits.t_max, limits.t_max, 12);
+
+        let mut data = vec![0u8; 8];
+        data[0] = (q_int >> 8) as u8;
+        data[1] = (q_int & 0xFF) as u8;
+        data[2] = (dq_int >> 4) as u8;
+        data[3] = ((dq_int & 0x0F) << 4) as u8 | ((kp_int >> 8) & 0x0F) as u8;
+        data[4] = (kp_int & 0xFF) as u8;
+        data[5] = (kd_int >> 4) as u8;
+        data[6] = ((kd_int & 0x0F) << 4) as u8 | ((tau_int >> 8) & 0x0F) as u8;
+        data[7] = (tau_int & 0xFF) as u8;
+
+        CANPacket {
+            send_can_id: motor.send_can_id(),
+            data,
+        }
+    }
+
+    /// Create position-velocity control command.
+    #[staticmethod]
+    pub fn create_posvel_control_command(motor: &Motor, param: &PosVelParam) -> CANPacket {
+        let limits = motor.motor_type().get_limits();
+
+        let q_field = ClampedField::clamp(param.q, -limits.p_max, limits.p_max);
+        let dq_field = ClampedField::clamp(param.dq, -limits.v_max, limits.v_max);
+        motor.store_clamp_report(ClampReport {
+            q: Some(q_field),
+            dq: Some(dq_field),
+            tau: None,
+            i: None,
+        });
+
+        let q_bytes = (q_field.applied * 10000.0) as i32;
+        let dq_bytes = (dq_field.applied * 10000.0) as i32;
+
+        let mut data = vec![0u8; 8];
+        data[0] = (q_bytes & 0xFF) as u8;
+        data[1] = ((q_bytes >> 8) & 0xFF) as u8;
+        data[2] = ((q_bytes >> 16) & 0xFF) as u8;
+        data[3] = ((q_bytes >> 24) & 0xFF) as u8;
+        data[4] = (dq_bytes & 0xFF) as u8;
+        data[5] = ((dq_bytes >> 8) & 0xFF) as u8;
+        data[6] = ((dq_bytes >> 16) & 0xFF) as u8;
+        data[7] = ((dq_bytes >> 24) & 0xFF) as u8;
+
+        CANPacket {
+            send_can_id: motor.send_can_id() + 0x100,
+            data,
+        }
+    }
+
+    /// Create position-force control command.
+    #[staticmethod]
+    pub fn create_posforce_control_command(motor: &Motor, param: &PosForceParam) -> CANPacket {
+        let limits = motor.motor_type().get_limits();
+
+        let q_field = ClampedField::clamp(param.q, -limits.p_max, limits.p_max);
+        let dq_field = ClampedField::clamp(param.dq, 0.0, limits.v_max);
+        let i_field = ClampedField::clamp(param.i, 0.0, 1.0);
+        motor.store_clamp_report(ClampReport {
+            q: Some(q_field),
+            dq: Some(dq_field),
+            tau: None,
+            i: Some(i_field),
+        });
+
+        let q_bytes = (q_field.applied * 10000.0) as i32;
+        let dq_scaled = (dq_field.applied * 100.0) as u16;
+        let i_scaled = (i_field.applied * 10000.0) as u16;
+
+        let mut data = vec![0u8; 8];
+        data[0] = (q_bytes & 0xFF) as u8;
+        data[1] = ((q_bytes >> 8) & 0xFF) as u8;
+        data[2] = ((q_bytes >> 16) & 0xFF) as u8;
+        data[3] = ((q_bytes >> 24) & 0xFF) as u8;
+        data[4] = (dq_scaled & 0xFF) as u8;
+        data[5] = ((dq_scaled >> 8) & 0xFF) as u8;
+        data[6] = (i_scaled & 0xFF) as u8;
+        data[7] = ((i_scaled >> 8) & 0xFF) as u8;
+
+        CANPacket {
+            send_can_id: motor.send_can_id() + 0x300,
+            data,
+        }
+    }
+
+    /// Create set control mode command.
+    #[staticmethod]
+    pub fn create_set_control_mode_command(motor: &Motor, mode: ControlMode) -> CANPacket {
+        let can_id = motor.send_can_id();
+
+        CANPacket {
+            send_can_id: 0x7FF,
+            data: vec![
+                (can_id & 0xFF) as u8,
+                ((can_id >> 8) & 0xFF) as u8,
+                0x55,
+                MotorVariable::CTRL_MODE as u8,
+                mode as u8,
+                0x00,
+                0x00,
+                0x00,
+            ],
+        }
+    }
+
+    /// Create query parameter command.
+    #[staticmethod]
+    pub fn create_query_param_command(motor: &Motor, rid: MotorVariable) -> CANPacket {
+        let can_id = motor.send_can_id();
+
+        CANPacket {
+            send_can_id: 0x7FF,
+            data: vec![
+                (can_id & 0xFF) as u8,
+                ((can_id >> 8) & 0xFF) as u8,
+                0x33,
+                rid as u8,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+            ],
+        }
+    }
+
+    /// Create write parameter command (writes `value` to register `rid`).
+    ///
+    /// Identifier and mode registers (`CTRL_MODE`, `MST_ID`, `ESC_ID`) are
+    /// serialized as a little-endian `i32`; every other register is an
+    /// `f32` - matching the decode in
+    /// [`parse_motor_param_data`](CanPacketDecoder::parse_motor_param_data).
+    #[staticmethod]
+    pub fn create_write_param_command(motor: &Motor, rid: MotorVariable, value: f64) -> CANPacket {
+        let can_id = motor.send_can_id();
+
+        let bytes = if rid == MotorVariable::CTRL_MODE
+            || rid == MotorVariable::MST_ID
+            || rid == MotorVariable::ESC_ID
+        {
+            (value as i32).to_le_bytes()
+        } else {
+            (value as f32).to_le_bytes()
+        };
+
+        CANPacket {
+            send_can_id: 0x7FF,
+            data: vec![
+                (can_id & 0xFF) as u8,
+                ((can_id >> 8) & 0xFF) as u8,
+                0x55,
+                rid as u8,
+                bytes[0],
+                bytes[1],
+                bytes[2],
+                bytes[3],
+            ],
+        }
+    }
+
+    /// Create a save-parameters-to-flash command, persisting the register
+    /// values most recently sent with
+    /// [`create_write_param_command`](Self::create_write_param_command) so
+    /// they survive a power cycle.
+    #[staticmethod]
+    pub fn create_save_params_command(motor: &Motor) -> CANPacket {
+        let can_id = motor.send_can_id();
+
+        CANPacket {
+            send_can_id: 0x7FF,
+            data: vec![
+                (can_id & 0xFF) as u8,
+                ((can_id >> 8) & 0xFF) as u8,
+                0xAA,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+            ],
+        }
+    }
+}
+
+/// CAN packet decoder for Damiao motor responses.
+#[pyclass]
+#[derive(Debug, Clone, Default)]
+pub struct CanPacketDecoder;
+
+#[pymethods]
+impl CanPacketDecoder {
+    /// Create a new decoder.
+    #[new]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Parse motor state data from a feedback frame.
+    #[staticmethod]
+    pub fn parse_motor_state_data(motor: &Motor, data: &[u8]) -> MotorStateResult {
+        Self::decode_state(motor, data)
+    }
+
+    /// Parse parameter data from a feedback frame.
+    #[staticmethod]
+    pub fn parse_motor_param_data(data: &[u8]) -> ParamResult {
+        Self::decode_param(data)
+    }
+
+    /// Decode a state frame and store the result on `motor`.
+    #[staticmethod]
+    pub fn parse_and_update_motor_state(motor: &Motor, data: &[u8]) -> bool {
+        let result = Self::decode_state(motor, data);
+        let valid = result.valid;
+        motor.update_state(result);
+        valid
+    }
+
+    /// Decode a parameter frame and store the result on `motor`.
+    #[staticmethod]
+    pub fn parse_and_store_param(motor: &Motor, data: &[u8]) -> bool {
+        let result = Self::decode_param(data);
+        let valid = result.valid;
+        if valid {
+            motor.store_param(result);
+        }
+        valid
+    }
+}
+
+impl CanPacketDecoder {
+    /// Decode a state frame.
+    ///
+    /// `data[0]` packs the low bits of the reporting motor's CAN id in the
+    /// low nibble and the amplifier's fault/run status in the high nibble;
+    /// the rest of the frame is the packed position/velocity/torque/
+    /// temperature payload.
+    fn decode_state(motor: &Motor, data: &[u8]) -> MotorStateResult {
+        if data.len() < 8 {
+            return MotorStateResult {
+                valid: false,
+                ..Default::default()
+            };
+        }
+
+        let limits = motor.motor_type().get_limits();
+
+        let motor_id = (data[0] & 0x0F) as u32;
+        let fault = MotorFault::from_code(data[0] >> 4);
+        let q_raw = ((data[1] as u32) << 8) | (data[2] as u32);
+        let dq_raw = ((data[3] as u32) << 4) | ((data[4] >> 4) as u32);
+        let tau_raw = (((data[4] & 0x0F) as u32) << 8) | (data[5] as u32);
+        let t_mos = data[6] as i32;
+        let t_rotor = data[7] as i32;
+
+        let position = uint_to_float(q_raw, -limits.p_max, limits.p_max, 16);
+        let velocity = uint_to_float(dq_raw, -limits.v_max, limits.v_max, 12);
+        let torque = uint_to_float(tau_raw, -limits.t_max, limits.t_max, 12);
+
+        MotorStateResult {
+            position,
+            velocity,
+            torque,
+            t_mos,
+            t_rotor,
+            motor_id,
+            fault,
+            valid: true,
+        }
+    }
+
+    /// Decode a parameter response frame.
+    fn decode_param(data: &[u8]) -> ParamResult {
+        if data.len() < 8 {
+            return ParamResult {
+                valid: false,
+                ..Default::default()
+            };
+        }
+
+        let rid = data[3] as i32;
+        let value_bytes = [data[4], data[5], data[6], data[7]];
+
+        // Identifier and mode registers are integers; everything else is a
+        // float - matching the layout `create_write_param_command` writes.
+        let value = if rid == MotorVariable::CTRL_MODE as i32
+            || rid == MotorVariable::MST_ID as i32
+            || rid == MotorVariable::ESC_ID as i32
+        {
+            i32::from_le_bytes(value_bytes) as f64
+        } else {
+            f32::from_le_bytes(value_bytes) as f64
+        };
+
+        ParamResult {
+            rid,
+            value,
+            valid: true,
+        }
+    }
+}
@@ -119,6 +119,18 @@ impl DMDeviceCollection {
         Ok(())
     }
 
+    /// MIT control for one motor, returning a report of which of `param`'s
+    /// fields were saturated against the motor's configured limits.
+    pub fn mit_control_one_checked(&self, index: usize, param: &MITParam) -> PyResult<ClampReport> {
+        let motor = self.motors.get(index).ok_or_else(|| {
+            pyo3::exceptions::PyIndexError::new_err(format!("Motor index {} out of range", index))
+        })?;
+        let packet = CanPacketEncoder::create_mit_control_command(motor, param);
+        self.collection.send_packet(packet.send_can_id, &packet.data)
+            .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Send error: {}", e)))?;
+        Ok(motor.get_clamp_report().unwrap_or_default())
+    }
+
     /// MIT control for all motors.
     pub fn mit_control_all(&self, params: Vec<MITParam>) -> PyResult<()> {
         if params.len() != self.motors.len() {
@@ -203,6 +215,127 @@ impl DMDeviceCollection {
         Ok(())
     }
 
+    /// Write a parameter for one motor.
+    pub fn write_param_one(&self, index: usize, rid: MotorVariable, value: f64) -> PyResult<()> {
+        let motor = self.motors.get(index).ok_or_else(|| {
+            pyo3::exceptions::PyIndexError::new_err(format!("Motor index {} out of range", index))
+        })?;
+        let packet = CanPacketEncoder::create_write_param_command(motor, rid, value);
+        self.collection.send_packet(packet.send_can_id, &packet.data)
+            .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Send error: {}", e)))?;
+        Ok(())
+    }
+
+    /// Write a parameter for all motors.
+    pub fn write_param_all(&self, rid: MotorVariable, value: f64) -> PyResult<()> {
+        for motor in &self.motors {
+            let packet = CanPacketEncoder::create_write_param_command(motor, rid, value);
+            self.collection.send_packet(packet.send_can_id, &packet.data)
+                .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Send error: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    /// Persist the current parameter set to flash for all motors.
+    pub fn save_params_all(&self) -> PyResult<()> {
+        for motor in &self.motors {
+            let packet = CanPacketEncoder::create_save_params_command(motor);
+            self.collection.send_packet(packet.send_can_id, &packet.data)
+                .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Send error: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    /// Last queried under-voltage protection threshold (volts), if a query
+    /// for it has been answered.
+    pub fn get_uv_value(&self, index: usize) -> PyResult<Option<f64>> {
+        self.get_protection_param(index, MotorVariable::UV_Value)
+    }
+
+    /// Set the under-voltage protection threshold (volts).
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_uv_value(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.set_fixed_range_param(index, MotorVariable::UV_Value, value, 0.0, 60.0, persist)
+    }
+
+    /// Last queried over-voltage protection threshold (volts), if a query for
+    /// it has been answered.
+    pub fn get_ov_value(&self, index: usize) -> PyResult<Option<f64>> {
+        self.get_protection_param(index, MotorVariable::OV_Value)
+    }
+
+    /// Set the over-voltage protection threshold (volts).
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_ov_value(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.set_fixed_range_param(index, MotorVariable::OV_Value, value, 0.0, 60.0, persist)
+    }
+
+    /// Last queried over-current trip point (amps), if a query for it has
+    /// been answered.
+    pub fn get_oc_value(&self, index: usize) -> PyResult<Option<f64>> {
+        self.get_protection_param(index, MotorVariable::OC_Value)
+    }
+
+    /// Set the over-current trip point (amps).
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_oc_value(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.set_fixed_range_param(index, MotorVariable::OC_Value, value, 0.0, 100.0, persist)
+    }
+
+    /// Last queried over-temperature trip point (degrees C), if a query for
+    /// it has been answered.
+    pub fn get_ot_value(&self, index: usize) -> PyResult<Option<f64>> {
+        self.get_protection_param(index, MotorVariable::OT_Value)
+    }
+
+    /// Set the over-temperature trip point (degrees C).
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_ot_value(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        self.set_fixed_range_param(index, MotorVariable::OT_Value, value, 0.0, 200.0, persist)
+    }
+
+    /// Last queried torque constant (N*m/A), if a query for it has been
+    /// answered.
+    pub fn get_kt_value(&self, index: usize) -> PyResult<Option<f64>> {
+        self.get_protection_param(index, MotorVariable::KT_Value)
+    }
+
+    /// Set the torque constant (N*m/A), bounded by the motor's rated torque
+    /// limit.
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_kt_value(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        let limit = self.motor_limit(index)?.t_max;
+        self.set_ranged_param(index, MotorVariable::KT_Value, value, 0.0, limit, persist)
+    }
+
+    /// Last queried acceleration limit (rad/s^2), if a query for it has been
+    /// answered.
+    pub fn get_acc(&self, index: usize) -> PyResult<Option<f64>> {
+        self.get_protection_param(index, MotorVariable::ACC)
+    }
+
+    /// Set the acceleration limit (rad/s^2), bounded by the motor's velocity
+    /// limit.
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_acc(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        let limit = self.motor_limit(index)?.v_max;
+        self.set_ranged_param(index, MotorVariable::ACC, value, 0.0, limit, persist)
+    }
+
+    /// Last queried deceleration limit (rad/s^2), if a query for it has been
+    /// answered.
+    pub fn get_dec(&self, index: usize) -> PyResult<Option<f64>> {
+        self.get_protection_param(index, MotorVariable::DEC)
+    }
+
+    /// Set the deceleration limit (rad/s^2), bounded by the motor's velocity
+    /// limit.
+    #[pyo3(signature = (index, value, persist=false))]
+    pub fn set_dec(&self, index: usize, value: f64, persist: bool) -> PyResult<()> {
+        let limit = self.motor_limit(index)?.v_max;
+        self.set_ranged_param(index, MotorVariable::DEC, value, 0.0, limit, persist)
+    }
+
     /// Set control mode for all motors.
     pub fn set_control_mode_all(&self, mode: ControlMode) -> PyResult<()> {
         for motor in &self.motors {
@@ -226,12 +359,86 @@ impl DMDeviceCollection {
         self.collection.recv_all(first_timeout_us)
     }
 
+    /// Index and fault of the first motor whose last decoded state reports a
+    /// fault, so a caller can abort instead of continuing to drive a tripped
+    /// joint.
+    pub fn first_fault(&self) -> Option<(usize, MotorFault)> {
+        self.motors.iter().enumerate().find_map(|(i, motor)| {
+            let fault = motor.get_state().fault;
+            fault.is_fault().then_some((i, fault))
+        })
+    }
+
     fn __repr__(&self) -> String {
         format!("DMDeviceCollection(motors={})", self.motors.len())
     }
 }
 
 impl DMDeviceCollection {
+    /// Resolve a motor by index or return the standard out-of-range error
+    /// (internal).
+    fn motor(&self, index: usize) -> PyResult<&Motor> {
+        self.motors.get(index).ok_or_else(|| {
+            pyo3::exceptions::PyIndexError::new_err(format!("Motor index {} out of range", index))
+        })
+    }
+
+    /// Software limits for the motor at `index` (internal).
+    fn motor_limit(&self, index: usize) -> PyResult<&'static LimitParam> {
+        Ok(self.motor(index)?.motor_type().get_limits())
+    }
+
+    /// Last queried value of a protection parameter, if any (internal).
+    fn get_protection_param(&self, index: usize, rid: MotorVariable) -> PyResult<Option<f64>> {
+        Ok(self.motor(index)?.get_param(rid as i32).map(|p| p.value))
+    }
+
+    /// Validate `value` against `[min, max]`, write it via `rid`, and
+    /// optionally persist it to flash (internal).
+    fn set_ranged_param(
+        &self,
+        index: usize,
+        rid: MotorVariable,
+        value: f64,
+        min: f64,
+        max: f64,
+        persist: bool,
+    ) -> PyResult<()> {
+        let motor = self.motor(index)?;
+        if value < min || value > max {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "value {} out of range [{}, {}] for {:?}",
+                value, min, max, rid
+            )));
+        }
+        let packet = CanPacketEncoder::create_write_param_command(motor, rid, value);
+        self.collection.send_packet(packet.send_can_id, &packet.data)
+            .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Send error: {}", e)))?;
+        if persist {
+            let packet = CanPacketEncoder::create_save_params_command(motor);
+            self.collection.send_packet(packet.send_can_id, &packet.data)
+                .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Send error: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    /// Validate `value` against a fixed, motor-type-independent range (internal).
+    ///
+    /// `MotorType`'s software limits only cover position/velocity/torque, so
+    /// voltage/current/temperature protection parameters are bounded by a
+    /// generous fixed range here rather than a per-motor-type limit.
+    fn set_fixed_range_param(
+        &self,
+        index: usize,
+        rid: MotorVariable,
+        value: f64,
+        min: f64,
+        max: f64,
+        persist: bool,
+    ) -> PyResult<()> {
+        self.set_ranged_param(index, rid, value, min, max, persist)
+    }
+
     /// Add a motor and its device (internal).
     pub(crate) fn add_motor_device(&mut self, motor: Motor, device: Arc<Mutex<MotorDeviceCan>>) {
         self.motors.push(motor);
@@ -2,12 +2,10 @@
 
 pub mod constants;
 pub mod control;
-pub mod device;
 pub mod device_collection;
 pub mod motor;
 
 pub use constants::*;
 pub use control::*;
-pub use device::*;
 pub use device_collection::*;
 pub use motor::*;
@@ -0,0 +1,138 @@
+//! Motor handle: static identity plus the live state decoded from its
+//! feedback frames.
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use super::constants::{ClampReport, ControlMode, MotorStateResult, MotorType, ParamResult};
+
+/// Live state shared between a `Motor` handle and its `MotorDeviceCan`
+/// callback (internal).
+#[derive(Debug, Default)]
+struct MotorInner {
+    state: MotorStateResult,
+    params: HashMap<i32, ParamResult>,
+    last_clamp: Option<ClampReport>,
+}
+
+/// A single Damiao motor.
+///
+/// Holds the CAN identity and control mode a caller configured it with, plus
+/// the most recently decoded state and parameter values. State is behind an
+/// `Arc<Mutex<_>>` so every clone of a `Motor` (e.g. the one held by its
+/// `MotorDeviceCan` and the one returned to Python) observes the same
+/// updates.
+#[pyclass]
+#[derive(Clone)]
+pub struct Motor {
+    motor_type: MotorType,
+    send_can_id: u32,
+    recv_can_id: u32,
+    control_mode: ControlMode,
+    inner: Arc<Mutex<MotorInner>>,
+}
+
+#[pymethods]
+impl Motor {
+    #[new]
+    pub fn new(
+        motor_type: MotorType,
+        send_can_id: u32,
+        recv_can_id: u32,
+        control_mode: ControlMode,
+    ) -> Self {
+        Self {
+            motor_type,
+            send_can_id,
+            recv_can_id,
+            control_mode,
+            inner: Arc::new(Mutex::new(MotorInner::default())),
+        }
+    }
+
+    /// Get the motor type.
+    #[getter]
+    pub fn get_motor_type(&self) -> MotorType {
+        self.motor_type
+    }
+
+    /// Get the send CAN ID.
+    #[getter]
+    pub fn get_send_can_id(&self) -> u32 {
+        self.send_can_id
+    }
+
+    /// Get the receive CAN ID.
+    #[getter]
+    pub fn get_recv_can_id(&self) -> u32 {
+        self.recv_can_id
+    }
+
+    /// Get the control mode.
+    #[getter]
+    pub fn get_control_mode(&self) -> ControlMode {
+        self.control_mode
+    }
+
+    /// Set the control mode (local bookkeeping only; does not notify the
+    /// motor - see `DMDeviceCollection::set_control_mode_one`).
+    pub fn set_control_mode(&mut self, mode: ControlMode) {
+        self.control_mode = mode;
+    }
+
+    /// Latest decoded state (position, velocity, torque, temperatures).
+    pub fn get_state(&self) -> MotorStateResult {
+        self.inner.lock().unwrap().state
+    }
+
+    /// Latest value received for `rid`, if a query has been answered.
+    pub fn get_param(&self, rid: i32) -> Option<ParamResult> {
+        self.inner.lock().unwrap().params.get(&rid).copied()
+    }
+
+    /// Clamp report from the most recently encoded control command, if any.
+    pub fn get_clamp_report(&self) -> Option<ClampReport> {
+        self.inner.lock().unwrap().last_clamp
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Motor(motor_type={:?}, send_can_id=0x{:X}, recv_can_id=0x{:X}, control_mode={:?})",
+            self.motor_type, self.send_can_id, self.recv_can_id, self.control_mode
+        )
+    }
+}
+
+impl Motor {
+    /// Get the send CAN ID (internal).
+    pub(crate) fn send_can_id(&self) -> u32 {
+        self.send_can_id
+    }
+
+    /// Get the receive CAN ID (internal).
+    pub(crate) fn recv_can_id(&self) -> u32 {
+        self.recv_can_id
+    }
+
+    /// Get the motor type (internal).
+    pub(crate) fn motor_type(&self) -> MotorType {
+        self.motor_type
+    }
+
+    /// Store a newly decoded state (internal).
+    pub(crate) fn update_state(&self, state: MotorStateResult) {
+        self.inner.lock().unwrap().state = state;
+    }
+
+    /// Store a newly decoded parameter value (internal).
+    pub(crate) fn store_param(&self, result: ParamResult) {
+        self.inner.lock().unwrap().params.insert(result.rid, result);
+    }
+
+    /// Store the clamp report produced by the most recent control command
+    /// encode (internal).
+    pub(crate) fn store_clamp_report(&self, report: ClampReport) {
+        self.inner.lock().unwrap().last_clamp = Some(report);
+    }
+}
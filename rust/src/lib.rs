@@ -10,12 +10,15 @@ pub mod canbus;
 pub mod components;
 pub mod damiao_motor;
 
-use canbus::{CANDevice, CANDeviceCollection, CANSocket, CanFdFrame, CanFrame, MotorDeviceCan, CANSocketException};
-use components::{ArmComponent, GripperComponent, OpenArm};
+use canbus::{
+    BusState, CANDevice, CANDeviceCollection, CANSocket, CANSocketException, CanErrorFrame,
+    CanFdFrame, CanFrame, MotorDeviceCan,
+};
+use components::{ArmComponent, GripperComponent, MotorReport, OpenArm};
 use damiao_motor::{
-    CANPacket, CallbackMode, CanPacketDecoder, CanPacketEncoder, ControlMode, DMDeviceCollection,
-    LimitParam, MITParam, Motor, MotorStateResult, MotorType, MotorVariable, ParamResult,
-    PosForceParam, PosVelParam,
+    CANPacket, CallbackMode, CanPacketDecoder, CanPacketEncoder, ClampReport, ClampedField,
+    ControlMode, DMDeviceCollection, LimitParam, MITParam, Motor, MotorFault, MotorStateResult,
+    MotorType, MotorVariable, ParamResult, PosForceParam, PosVelParam,
 };
 
 /// OpenArm CAN Python module.
@@ -26,6 +29,7 @@ fn openarm_can(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<MotorVariable>()?;
     m.add_class::<CallbackMode>()?;
     m.add_class::<ControlMode>()?;
+    m.add_class::<MotorFault>()?;
 
     // Data structures
     m.add_class::<LimitParam>()?;
@@ -33,10 +37,14 @@ fn openarm_can(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<MotorStateResult>()?;
     m.add_class::<CanFrame>()?;
     m.add_class::<CanFdFrame>()?;
+    m.add_class::<CanErrorFrame>()?;
+    m.add_class::<BusState>()?;
     m.add_class::<MITParam>()?;
     m.add_class::<PosVelParam>()?;
     m.add_class::<PosForceParam>()?;
     m.add_class::<CANPacket>()?;
+    m.add_class::<ClampedField>()?;
+    m.add_class::<ClampReport>()?;
 
     // Classes
     m.add_class::<Motor>()?;
@@ -46,6 +54,7 @@ fn openarm_can(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<CANDeviceCollection>()?;
     m.add_class::<DMDeviceCollection>()?;
     m.add_class::<ArmComponent>()?;
+    m.add_class::<MotorReport>()?;
     m.add_class::<GripperComponent>()?;
     m.add_class::<OpenArm>()?;
     m.add_class::<CanPacketEncoder>()?;